@@ -0,0 +1,69 @@
+//! Implementations of the `approx` crate's comparison traits, so that matrices can be
+//! compared with a tolerance using e.g. `assert_abs_diff_eq!`. Everything in here is gated
+//! behind the `approx` feature.
+//!
+//! Two matrices are considered equal if every pair of corresponding entries is.
+
+use crate::Matrix;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+impl<T, const N: usize, const M: usize> AbsDiffEq for Matrix<T, N, M>
+where
+    [(); N * M]:,
+    T: AbsDiffEq,
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl<T, const N: usize, const M: usize> RelativeEq for Matrix<T, N, M>
+where
+    [(); N * M]:,
+    T: RelativeEq,
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl<T, const N: usize, const M: usize> UlpsEq for Matrix<T, N, M>
+where
+    [(); N * M]:,
+    T: UlpsEq,
+    T::Epsilon: Copy,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
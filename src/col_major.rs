@@ -0,0 +1,122 @@
+//! A matrix stored in column-major order. `Matrix` stores its entries row by row, which
+//! makes reading down a column (as the right-hand side of a multiplication does) jump
+//! around in memory. Storing the right-hand side column by column instead means both
+//! operands are read sequentially.
+
+use crate::{check_bounds, DimensionAssertion, IsTrue, Matrix};
+use num_traits::Zero;
+use std::ops::{Add, Index, IndexMut, Mul};
+
+/// A matrix whose entries are stored column by column. Logically it behaves exactly like
+/// a `Matrix`, and it can be converted to and from one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColMajorMatrix<T, const N: usize, const M: usize>
+where
+    [(); N * M]:,
+{
+    /// The array representing the data. The items are sorted from top to bottom, next
+    /// from left to right.
+    pub data: [T; N * M],
+}
+
+impl<T, const N: usize, const M: usize> ColMajorMatrix<T, N, M>
+where
+    [(); N * M]:,
+{
+    /// Constructs a matrix directly from an array in column-major order.
+    pub fn from_array(data: [T; N * M]) -> Self {
+        ColMajorMatrix { data }
+    }
+
+    /// Returns the entries of a given column as a slice. Since columns are stored
+    /// contiguously, this doesn't need to copy anything.
+    pub fn col_slice(&self, c: usize) -> &[T] {
+        assert!(
+            c < M,
+            "Index out of bounds: specified column {c} but matrix only has {M} columns."
+        );
+        &self.data[c * N..(c + 1) * N]
+    }
+
+    /// Reinterprets the matrix as the row-major `Matrix` of its transpose. The data
+    /// isn't moved at all.
+    pub fn transpose(self) -> Matrix<T, M, N>
+    where
+        [(); M * N]:,
+    {
+        Matrix::from_array(std::array::from_fn({
+            let mut data = self.data.into_iter();
+            move |_| data.next().unwrap()
+        }))
+    }
+}
+
+impl<T, const N: usize, const M: usize> Index<(usize, usize)> for ColMajorMatrix<T, N, M>
+where
+    [(); N * M]:,
+{
+    type Output = T;
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        check_bounds::<N, M>(index);
+        &self.data[index.1 * N + index.0]
+    }
+}
+
+impl<T, const N: usize, const M: usize> IndexMut<(usize, usize)> for ColMajorMatrix<T, N, M>
+where
+    [(); N * M]:,
+{
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        check_bounds::<N, M>(index);
+        &mut self.data[index.1 * N + index.0]
+    }
+}
+
+impl<T, const N: usize, const M: usize> From<Matrix<T, N, M>> for ColMajorMatrix<T, N, M>
+where
+    [(); N * M]:,
+    T: Copy,
+{
+    /// Converts a row-major matrix, reordering its data.
+    fn from(value: Matrix<T, N, M>) -> Self {
+        ColMajorMatrix::from_array(std::array::from_fn(|i| value[(i % N, i / N)]))
+    }
+}
+
+impl<T, const N: usize, const M: usize> From<ColMajorMatrix<T, N, M>> for Matrix<T, N, M>
+where
+    [(); N * M]:,
+    T: Copy,
+{
+    /// Converts into a row-major matrix, reordering its data.
+    fn from(value: ColMajorMatrix<T, N, M>) -> Self {
+        Matrix::from_array(std::array::from_fn(|i| value[(i / M, i % M)]))
+    }
+}
+
+impl<T, Q, R, const N: usize, const M: usize, const O: usize, const P: usize>
+    Mul<ColMajorMatrix<Q, O, P>> for Matrix<T, N, M>
+where
+    T: Copy + Mul<Q, Output = R>,
+    Q: Copy,
+    R: Add + Zero + Copy,
+    [(); N * M]:,
+    [(); O * P]:,
+    [(); N * P]:,
+    DimensionAssertion<{ M == O }>: IsTrue,
+{
+    type Output = Matrix<R, N, P>;
+    /// Multiplies a row-major matrix by a column-major one. Every entry of the result
+    /// is the dot product of a row of `self` and a column of `rhs`, both of which are
+    /// contiguous in memory.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: ColMajorMatrix<Q, O, P>) -> Self::Output {
+        Matrix::from_array(std::array::from_fn(|i| {
+            let (x, y) = (i / P, i % P);
+            self.data[x * M..(x + 1) * M]
+                .iter()
+                .zip(rhs.col_slice(y))
+                .fold(R::zero(), |dot_product, (&a, &b)| dot_product + a * b)
+        }))
+    }
+}
@@ -0,0 +1,377 @@
+//! Support for complex element types. Everything in here is gated behind the `complex` feature.
+
+use crate::{DimensionAssertion, IsTrue, Matrix, MAX_ITERATIONS};
+use num_complex::Complex;
+use num_traits::{Float, Num, Zero};
+use std::ops::Neg;
+
+/// A type which has a notion of a complex conjugate. For real numbers the conjugate
+/// is just the number itself.
+pub trait Conjugate {
+    /// Returns the complex conjugate.
+    fn conjugate(&self) -> Self;
+}
+
+impl<T> Conjugate for Complex<T>
+where
+    T: Clone + Num + Neg<Output = T>,
+{
+    fn conjugate(&self) -> Self {
+        self.conj()
+    }
+}
+
+macro_rules! impl_real_conjugate {
+    ($($t:ty),*) => {
+        $(
+            impl Conjugate for $t {
+                fn conjugate(&self) -> Self {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+impl_real_conjugate!(f32, f64, i8, i16, i32, i64, i128, isize);
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    [(); N * M]:,
+    [(); M * N]:,
+    T: Conjugate + Copy + Zero,
+{
+    /// Returns the conjugate transpose (also known as the Hermitian adjoint) of the matrix:
+    /// the transpose, with every entry replaced by its complex conjugate.
+    ///
+    /// Not to be confused with the classical adjoint, which is called `adjugate` here.
+    pub fn adjoint(&self) -> Matrix<T, M, N> {
+        let mut result = self.transpose();
+        for e in result.data.iter_mut() {
+            *e = e.conjugate();
+        }
+        result
+    }
+}
+
+impl<T, const N: usize> Matrix<Complex<T>, N, N>
+where
+    [(); N * N]:,
+    T: Float,
+{
+    /// Checks if a square complex matrix is Hermitian, i.e. equal to its own conjugate
+    /// transpose. Since floating-point entries rarely line up exactly, two entries are
+    /// considered equal if the modulus of their difference is at most `tol`.
+    pub fn is_hermitian(&self, tol: T) -> bool {
+        let adjoint = self.adjoint();
+        self.data
+            .iter()
+            .zip(adjoint.data.iter())
+            .all(|(a, b)| (a - b).norm() <= tol)
+    }
+}
+
+/// Reduces a square matrix to upper Hessenberg form (zero below the subdiagonal) with
+/// Householder reflections. The result is similar to the original matrix, so it has the
+/// same eigenvalues.
+fn hessenberg<T: Float, const N: usize>(mut a: Matrix<T, N, N>) -> Matrix<T, N, N>
+where
+    [(); N * N]:,
+{
+    let two = T::one() + T::one();
+    for k in 0..N.saturating_sub(2) {
+        let norm = (k + 1..N)
+            .fold(T::zero(), |acc, i| acc + a[(i, k)] * a[(i, k)])
+            .sqrt();
+        if norm.is_zero() {
+            continue;
+        }
+        let alpha = if a[(k + 1, k)] >= T::zero() {
+            -norm
+        } else {
+            norm
+        };
+        let mut v: Vec<T> = (k + 1..N).map(|i| a[(i, k)]).collect();
+        v[0] = v[0] - alpha;
+        let v_norm_squared = v.iter().fold(T::zero(), |acc, &e| acc + e * e);
+        if v_norm_squared.is_zero() {
+            continue;
+        }
+
+        // A = HAH, where H = I - 2vvᵀ / vᵀv acts on the rows and columns after k.
+        for j in 0..N {
+            let dot = (0..v.len()).fold(T::zero(), |acc, i| acc + v[i] * a[(k + 1 + i, j)]);
+            let factor = two * dot / v_norm_squared;
+            for i in 0..v.len() {
+                a[(k + 1 + i, j)] = a[(k + 1 + i, j)] - factor * v[i];
+            }
+        }
+        for i in 0..N {
+            let dot = (0..v.len()).fold(T::zero(), |acc, l| acc + a[(i, k + 1 + l)] * v[l]);
+            let factor = two * dot / v_norm_squared;
+            for l in 0..v.len() {
+                a[(i, k + 1 + l)] = a[(i, k + 1 + l)] - factor * v[l];
+            }
+        }
+        for i in k + 2..N {
+            a[(i, k)] = T::zero();
+        }
+    }
+    a
+}
+
+#[allow(clippy::eq_op)]
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    [(); N * N]:,
+    T: Float,
+    DimensionAssertion<{ N == N }>: IsTrue,
+{
+    /// Calculates the eigenvalues of the matrix. Symmetric matrices are handed off to
+    /// `symmetric_eigen`, whose eigenvalues are all real; other matrices go through
+    /// `eigenvalues_qr`, which can find complex conjugate pairs as well.
+    ///
+    /// If the matrix has any infinite or NaN entries, every eigenvalue is NaN.
+    ///
+    /// ## Panics
+    ///
+    /// If `eigenvalues_qr` doesn't converge within `100 * N` iterations. With the
+    /// exceptional shifts that is very rare for finite matrices, but not impossible.
+    pub fn eigenvalues(&self) -> Vec<Complex<T>> {
+        if self.data.iter().any(|e| !e.is_finite()) {
+            vec![Complex::new(T::nan(), T::nan()); N]
+        } else if self.is_symmetric() {
+            let (eigenvalues, _) = self.symmetric_eigen();
+            eigenvalues
+                .into_iter()
+                .map(|e| Complex::new(e, T::zero()))
+                .collect()
+        } else {
+            self.eigenvalues_qr(MAX_ITERATIONS * N)
+                .expect("The QR iteration did not converge.")
+        }
+    }
+
+    /// Calculates the eigenvalues of a general real matrix by QR iteration with Francis
+    /// double shifts, making at most `iters` iterations.
+    ///
+    /// The matrix is first reduced to upper Hessenberg form. Every iteration then applies
+    /// two QR steps at once, shifted by both eigenvalues of the bottom 2×2 block of the
+    /// unconverged part. If those are a complex conjugate pair, the combined step still
+    /// only involves real arithmetic, so complex eigenvalues are found as quickly as real
+    /// ones. The steps are done implicitly, by chasing a small bulge down the subdiagonal
+    /// with 3×3 Householder reflections. Whenever a subdiagonal entry becomes negligible
+    /// the problem splits, and isolated 1×1 and 2×2 blocks are read off directly. If an
+    /// iteration gets stuck, as it does for orthogonal matrices like a cyclic permutation,
+    /// every tenth iteration on the same block uses an ad hoc shift to break the symmetry.
+    ///
+    /// Returns `None` if the iteration hasn't converged within `iters` iterations.
+    pub fn eigenvalues_qr(&self, iters: usize) -> Option<Vec<Complex<T>>> {
+        let mut a = hessenberg(*self);
+        let zero = T::zero();
+        let two = T::one() + T::one();
+        let sign = |magnitude: T, of: T| {
+            if of >= zero {
+                magnitude.abs()
+            } else {
+                -magnitude.abs()
+            }
+        };
+        let norm = (0..N).fold(zero, |acc, i| {
+            (i.saturating_sub(1)..N).fold(acc, |acc, j| acc + a[(i, j)].abs())
+        });
+
+        let mut eigenvalues = vec![Complex::new(zero, zero); N];
+        // The sum of the exceptional shifts, which have been subtracted from the diagonal.
+        let mut offset = zero;
+        let mut block_iterations = 0;
+        let mut total_iterations = 0;
+        // Everything from `end` onwards has converged.
+        let mut end = N;
+        while end > 0 {
+            let last = end - 1;
+            // Look for a negligible subdiagonal entry; the active block starts below it.
+            let mut start = last;
+            while start > 0 {
+                let mut scale = a[(start - 1, start - 1)].abs() + a[(start, start)].abs();
+                if scale.is_zero() {
+                    scale = norm;
+                }
+                if a[(start, start - 1)].abs() <= T::epsilon() * scale {
+                    a[(start, start - 1)] = zero;
+                    break;
+                }
+                start -= 1;
+            }
+
+            let mut x = a[(last, last)];
+            if start == last {
+                eigenvalues[last] = Complex::new(x + offset, zero);
+                end -= 1;
+                block_iterations = 0;
+                continue;
+            }
+            let mut y = a[(last - 1, last - 1)];
+            let mut w = a[(last, last - 1)] * a[(last - 1, last)];
+            if start == last - 1 {
+                // An isolated 2×2 block. The roots are computed so that neither of them
+                // suffers from cancellation.
+                let p = (y - x) / two;
+                let q = p * p + w;
+                let root = q.abs().sqrt();
+                x = x + offset;
+                if q >= zero {
+                    let z = p + sign(root, p);
+                    eigenvalues[last - 1] = Complex::new(x + z, zero);
+                    eigenvalues[last] = if z.is_zero() {
+                        Complex::new(x + z, zero)
+                    } else {
+                        Complex::new(x - w / z, zero)
+                    };
+                } else {
+                    eigenvalues[last - 1] = Complex::new(x + p, root);
+                    eigenvalues[last] = Complex::new(x + p, -root);
+                }
+                end -= 2;
+                block_iterations = 0;
+                continue;
+            }
+
+            if total_iterations == iters {
+                return None;
+            }
+            if block_iterations > 0 && block_iterations % 10 == 0 {
+                offset = offset + x;
+                for i in 0..end {
+                    a[(i, i)] = a[(i, i)] - x;
+                }
+                let s = a[(last, last - 1)].abs() + a[(last - 1, last - 2)].abs();
+                x = T::from(0.75).unwrap() * s;
+                y = x;
+                w = T::from(-0.4375).unwrap() * s * s;
+            }
+            block_iterations += 1;
+            total_iterations += 1;
+
+            // The first column of (A - μ₁I)(A - μ₂I) has only three nonzero entries, p, q
+            // and r. Start the bulge at the lowest row where two consecutive subdiagonal
+            // entries are small enough that it can start there without disturbing the
+            // rest.
+            let (mut p, mut q, mut r, mut z);
+            let mut m = last - 2;
+            loop {
+                z = a[(m, m)];
+                r = x - z;
+                let s = y - z;
+                p = (r * s - w) / a[(m + 1, m)] + a[(m, m + 1)];
+                q = a[(m + 1, m + 1)] - z - r - s;
+                r = a[(m + 2, m + 1)];
+                let s = p.abs() + q.abs() + r.abs();
+                p = p / s;
+                q = q / s;
+                r = r / s;
+                if m == start {
+                    break;
+                }
+                let u = a[(m, m - 1)].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (a[(m - 1, m - 1)].abs() + z.abs() + a[(m + 1, m + 1)].abs());
+                if u <= T::epsilon() * v {
+                    break;
+                }
+                m -= 1;
+            }
+            for i in m + 2..=last {
+                a[(i, i - 2)] = zero;
+                if i != m + 2 {
+                    a[(i, i - 3)] = zero;
+                }
+            }
+
+            // Chase the bulge down to the bottom of the block.
+            for k in m..last {
+                if k != m {
+                    p = a[(k, k - 1)];
+                    q = a[(k + 1, k - 1)];
+                    r = if k + 1 != last {
+                        a[(k + 2, k - 1)]
+                    } else {
+                        zero
+                    };
+                    x = p.abs() + q.abs() + r.abs();
+                    if !x.is_zero() {
+                        p = p / x;
+                        q = q / x;
+                        r = r / x;
+                    }
+                }
+                let s = sign((p * p + q * q + r * r).sqrt(), p);
+                if s.is_zero() {
+                    continue;
+                }
+                if k == m {
+                    if start != m {
+                        a[(k, k - 1)] = -a[(k, k - 1)];
+                    }
+                } else {
+                    a[(k, k - 1)] = -s * x;
+                }
+                p = p + s;
+                x = p / s;
+                y = q / s;
+                z = r / s;
+                q = q / p;
+                r = r / p;
+                for j in k..end {
+                    p = a[(k, j)] + q * a[(k + 1, j)];
+                    if k + 1 != last {
+                        p = p + r * a[(k + 2, j)];
+                        a[(k + 2, j)] = a[(k + 2, j)] - p * z;
+                    }
+                    a[(k + 1, j)] = a[(k + 1, j)] - p * y;
+                    a[(k, j)] = a[(k, j)] - p * x;
+                }
+                for i in start..=last.min(k + 3) {
+                    p = x * a[(i, k)] + y * a[(i, k + 1)];
+                    if k + 1 != last {
+                        p = p + z * a[(i, k + 2)];
+                        a[(i, k + 2)] = a[(i, k + 2)] - p * r;
+                    }
+                    a[(i, k + 1)] = a[(i, k + 1)] - p * q;
+                    a[(i, k)] = a[(i, k)] - p;
+                }
+            }
+        }
+        Some(eigenvalues)
+    }
+}
+
+/// Finds all roots of the polynomial `aₙ₋₁xᴺ⁻¹ + ... + a₁x + a₀`, whose coefficients are
+/// given in ascending order as `[a₀, a₁, ..., aₙ₋₁]`. The polynomial is first divided by
+/// its leading coefficient, after which the roots are calculated as the eigenvalues of
+/// its companion matrix.
+///
+/// The roots are the exact roots of a polynomial whose coefficients are close to the
+/// given ones, but how close that puts them to the true roots depends on how sensitive
+/// the roots are to the coefficients. Simple, well-separated roots usually come out
+/// accurate to many digits, while repeated roots are ill-conditioned: a root of
+/// multiplicity k is only found to within about the k-th root of the machine epsilon.
+///
+/// ## Panics
+///
+/// If the leading coefficient is zero.
+#[allow(clippy::eq_op)]
+pub fn roots<T, const N: usize>(coefficients: &[T; N]) -> Vec<Complex<T>>
+where
+    T: Float,
+    [(); N - 1]:,
+    [(); (N - 1) * (N - 1)]:,
+    DimensionAssertion<{ N - 1 == N - 1 }>: IsTrue,
+{
+    let leading = coefficients[N - 1];
+    assert!(
+        !leading.is_zero(),
+        "The leading coefficient of the polynomial is zero."
+    );
+    let monic: [T; N - 1] = std::array::from_fn(|i| coefficients[i] / leading);
+    Matrix::<T, { N - 1 }, { N - 1 }>::companion(&monic).eigenvalues()
+}
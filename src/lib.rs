@@ -18,9 +18,16 @@ feature; there is no other way to provide compile-time multiplicability or inver
 #![doc(test(attr(feature(generic_const_exprs))))]
 #![feature(generic_const_exprs)]
 #![deny(missing_docs)]
-use num_traits::{NumOps, One, Signed, Zero};
+use num_traits::{
+    CheckedAdd, CheckedMul, Float, NumOps, One, PrimInt, SaturatingAdd, SaturatingMul, Signed,
+    WrappingAdd, WrappingMul, Zero,
+};
+use std::collections::HashMap;
 use std::fmt::{self, Debug, Display};
-use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+use std::hash::Hash;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
 /// The following is some weird shit. This enum is generic over a boolean condition.
 /// It then only implements the IsTrue trait for `DimensionAssertion<true>`, so that
@@ -41,16 +48,13 @@ where
     /// from left to right, next from top to bottom. It's generally not recommended to
     /// access this array directly. Instead, indexing into the matrix using a tuple of `usize`s.
     pub data: [T; N * M],
-    /// The number of rows in the matrix. Note that though this is typechecked
-    /// it is often still useful to just get the number of rows in a matrix by accessing
-    /// a field.
-    pub rows: usize,
-    /// The number of columns in the matrix. Note that though this is typechecked
-    /// it is often still useful to just get the number of columns in a matrix by accessing
-    /// a field.
-    pub cols: usize,
 }
 
+/// A column vector, i.e. a matrix with a single column.
+pub type ColVector<T, const N: usize> = Matrix<T, N, 1>;
+/// A row vector, i.e. a matrix with a single row.
+pub type RowVector<T, const N: usize> = Matrix<T, 1, N>;
+
 /// Convenience stuff.
 impl<T, const N: usize, const M: usize> Index<(usize, usize)> for Matrix<T, N, M>
 where
@@ -58,19 +62,8 @@ where
 {
     type Output = T;
     fn index(&self, index: (usize, usize)) -> &Self::Output {
-        assert!(
-            index.0 < self.rows,
-            "Tried indexing into row {}, which outside of the matrix (has {} rows).",
-            index.0,
-            self.rows
-        );
-        assert!(
-            index.1 < self.cols,
-            "Tried indexing into column {}, which outside of the matrix (has {} column).",
-            index.1,
-            self.cols
-        );
-        &self.data[index.0 * self.cols + index.1]
+        check_bounds::<N, M>(index);
+        &self.data[index.0 * M + index.1]
     }
 }
 
@@ -79,7 +72,95 @@ where
     [(); N * M]:,
 {
     fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
-        &mut self.data[index.0 * self.cols + index.1]
+        check_bounds::<N, M>(index);
+        &mut self.data[index.0 * M + index.1]
+    }
+}
+
+/// Panics if `(row, column)` lies outside of an N by M matrix.
+fn check_bounds<const N: usize, const M: usize>((r, c): (usize, usize)) {
+    assert!(
+        r < N && c < M,
+        "Index out of bounds: tried to access ({r}, {c}) but matrix has {N} rows and {M} columns."
+    );
+}
+
+/// Compares two floats, ranking NaN above every other value (like `f64::total_cmp`)
+/// instead of giving up, so that sorting and pivot searches can't panic on it.
+fn nan_last<T: Float>(a: &T, b: &T) -> std::cmp::Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (false, false) => a.partial_cmp(b).unwrap(),
+        (a_nan, b_nan) => a_nan.cmp(&b_nan),
+    }
+}
+
+impl<T, const N: usize, const M: usize> AsRef<[T]> for Matrix<T, N, M>
+where
+    [(); N * M]:,
+{
+    /// Returns the elements of the matrix as a slice, in 'reading order'.
+    fn as_ref(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T, const N: usize, const M: usize> AsMut<[T]> for Matrix<T, N, M>
+where
+    [(); N * M]:,
+{
+    /// Returns the elements of the matrix as a mutable slice, in 'reading order'.
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+}
+
+/// Vectors (row or column) can be indexed using a single `usize`.
+// Note the non-short-circuiting `|`: `||` isn't allowed in generic constants.
+impl<T, const N: usize, const M: usize> Index<usize> for Matrix<T, N, M>
+where
+    [(); N * M]:,
+    DimensionAssertion<{ (N == 1) | (M == 1) }>: IsTrue,
+{
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        check_vector_bounds::<N, M>(index);
+        &self.data[index]
+    }
+}
+
+impl<T, const N: usize, const M: usize> IndexMut<usize> for Matrix<T, N, M>
+where
+    [(); N * M]:,
+    DimensionAssertion<{ (N == 1) | (M == 1) }>: IsTrue,
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        check_vector_bounds::<N, M>(index);
+        &mut self.data[index]
+    }
+}
+
+/// Panics if `index` lies outside of a vector with N * M elements.
+fn check_vector_bounds<const N: usize, const M: usize>(index: usize) {
+    assert!(
+        index < N * M,
+        "Tried indexing into element {index}, which is outside of the vector (has {} elements).",
+        N * M
+    );
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    [(); N * M]:,
+    DimensionAssertion<{ (N == 1) | (M == 1) }>: IsTrue,
+{
+    /// Returns the number of elements in a (row or column) vector.
+    pub fn len(&self) -> usize {
+        N * M
+    }
+
+    /// Checks if a (row or column) vector has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 }
 
@@ -109,7 +190,7 @@ where
             .map(|s| format!("{:0l$} ", s, l = longest))
             .collect::<Vec<String>>();
 
-        for row in padded_string_reps.chunks_exact(self.cols) {
+        for row in padded_string_reps.chunks_exact(M) {
             writeln!(
                 f,
                 "{}",
@@ -124,13 +205,126 @@ where
     }
 }
 
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    T: Display,
+    [(); N * M]:,
+{
+    /// Formats the matrix with custom delimiters: `col_sep` goes between the entries of a
+    /// row, and `row_sep` goes between rows. Unlike `Display`, no padding is added, so
+    /// this can produce e.g. tab-separated values.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+    /// assert_eq!(m.to_string_grid(", ", "; "), "1, 2; 3, 4");
+    /// ```
+    pub fn to_string_grid(&self, col_sep: &str, row_sep: &str) -> String {
+        // Every row is empty, but there are still N of them to separate.
+        if M == 0 {
+            return vec![""; N].join(row_sep);
+        }
+        self.data
+            .chunks_exact(M)
+            .map(|row| {
+                row.iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>()
+                    .join(col_sep)
+            })
+            .collect::<Vec<_>>()
+            .join(row_sep)
+    }
+}
+
 impl<T, const N: usize, const M: usize> Matrix<T, N, M>
 where
     [(); N * M]:,
 {
+    /// Constructs a matrix directly from an array in 'reading order'. No copying
+    /// or checking is needed here, since the array size is enforced by the type system.
+    pub fn from_array(data: [T; N * M]) -> Self {
+        Matrix { data }
+    }
+
+    /// Consumes the matrix and returns the underlying array. This is the inverse of
+    /// `from_array`.
+    pub fn into_array(self) -> [T; N * M] {
+        self.data
+    }
+
+    /// Constructs a matrix from a closure, which takes in the zero-indexed row and column
+    /// of every entry. Unlike `from_closure` this doesn't need to fill the matrix with
+    /// zeroes first, so it works for any element type.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<String, 1, 2> = Matrix::from_fn(|x, y| format!("{x}{y}"));
+    /// assert_eq!(m[(0, 1)], "01");
+    /// ```
+    pub fn from_fn<F>(mut func: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        // `std::array::from_fn` writes into uninitialised memory, so nothing has to be
+        // constructed up front.
+        Matrix::from_array(std::array::from_fn(|i| func(i / M, i % M)))
+    }
+
+    /// Constructs a matrix from a fallible closure, visiting the entries in 'reading
+    /// order'. As soon as the closure returns an error, construction stops and that error
+    /// is returned.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let cells = ["1", "2", "x", "4"];
+    /// let m: Result<Matrix<i32, 2, 2>, _> =
+    ///     Matrix::try_from_closure(2, 2, |x, y| cells[2 * x + y].parse::<i32>());
+    /// assert!(m.is_err());
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// If the specified rows and columns don't match the Matrix type.
+    pub fn try_from_closure<F, E>(r: usize, c: usize, func: F) -> Result<Self, E>
+    where
+        F: Fn(usize, usize) -> Result<T, E>,
+    {
+        assert_eq!(
+            N, r,
+            "Dimensionality of the matrix does not hold: rows do not match."
+        );
+        assert_eq!(
+            M, c,
+            "Dimensionality of the matrix does not hold: columns do not match."
+        );
+
+        let data = (0..N * M)
+            .map(|i| func(i / M, i % M))
+            .collect::<Result<Vec<T>, E>>()?;
+        Ok(data.into_iter().collect())
+    }
+
+    /// The number of rows in the matrix. This is just `N`, but it can be more convenient
+    /// than naming the const parameter.
+    pub const fn rows(&self) -> usize {
+        N
+    }
+
+    /// The number of columns in the matrix, i.e. `M`.
+    pub const fn cols(&self) -> usize {
+        M
+    }
+
     /// Checks if the matrix is square.
     pub fn is_square(&self) -> bool {
-        self.rows == self.cols
+        N == M
     }
 
     /// Executes a closure returning a boolean on every item in the matrix. Returns `true`
@@ -150,6 +344,75 @@ where
     {
         self.data.iter().any(func)
     }
+
+    /// Returns an iterator over the elements of the matrix, in 'reading order'.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements of the matrix,
+    /// in 'reading order'.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Returns an iterator over the elements of the matrix together with their
+    /// `(row, column)` coordinates, in 'reading order'. Like `iter`, its length is known
+    /// up front.
+    pub fn iter_indexed(&self) -> impl ExactSizeIterator<Item = ((usize, usize), &T)> {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, e)| ((i / M, i % M), e))
+    }
+
+    /// Folds every element of the matrix into an accumulator, in 'reading order'. Unlike
+    /// `Iterator::fold`, the closure also gets the `(row, column)` coordinates of every
+    /// element, which makes things like weighted sums easy.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+    /// // the sum of the entries on a checkerboard's black squares
+    /// let black = m.fold_indexed(0, |acc, (x, y), e| if (x + y) % 2 == 0 { acc + e } else { acc });
+    /// assert_eq!(black, 5);
+    /// ```
+    pub fn fold_indexed<B, F>(&self, init: B, func: F) -> B
+    where
+        F: Fn(B, (usize, usize), &T) -> B,
+    {
+        self.iter_indexed()
+            .fold(init, |acc, (index, e)| func(acc, index, e))
+    }
+
+    /// Consumes the matrix and returns an iterator over its rows, each as a standalone
+    /// 1×M matrix.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+    /// let rows: Vec<Matrix<i32, 1, 2>> = m.into_rows().collect();
+    /// assert_eq!(rows[1], Matrix::from(vec![3, 4]));
+    /// ```
+    #[allow(clippy::identity_op)]
+    pub fn into_rows(self) -> impl ExactSizeIterator<Item = Matrix<T, 1, M>>
+    where
+        [(); 1 * M]:,
+    {
+        let mut data = self.data.into_iter();
+        (0..N).map(move |_| Matrix::from_array(std::array::from_fn(|_| data.next().unwrap())))
+    }
+
+    /// Swaps two elements of the matrix. Mutates the matrix in-place.
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) {
+        check_bounds::<N, M>(a);
+        check_bounds::<N, M>(b);
+        self.data.swap(a.0 * M + a.1, b.0 * M + b.1);
+    }
 }
 
 impl<T, const N: usize, const M: usize> Matrix<T, N, M>
@@ -182,710 +445,3657 @@ where
         }
         col
     }
-}
 
-impl<T, const N: usize, const M: usize> From<Vec<Vec<T>>> for Matrix<T, N, M>
-where
-    T: Copy,
-    [(); N * M]:,
-{
-    fn from(value: Vec<Vec<T>>) -> Matrix<T, N, M> {
-        assert!(
-            value.iter().all(|row| row.len() == value[0].len()),
-            "Not all rows have the same length."
+    /// Assembles a matrix from its rows.
+    ///
+    /// ## Panics
+    ///
+    /// If there aren't exactly N rows.
+    pub fn from_rows<I: IntoIterator<Item = [T; M]>>(rows: I) -> Self {
+        let rows: Vec<[T; M]> = rows.into_iter().collect();
+        assert_eq!(
+            rows.len(),
+            N,
+            "Dimensionality does not hold: specified {} rows but Matrix type is supposed to have {N} rows.",
+            rows.len()
         );
-        assert_eq!(value.len(), N, "Dimensionality does not hold: specified a vector with {} elements but Matrix type is supposed to have {N} rows.", value.len());
-        assert_eq!(value[0].len(), M, "Dimensionality does not hold: specified a vector whose elements have {} elements but Matrix type is supposed to have {M} columns.", value[0].len());
-
-        let mut data = [value[0][0]; N * M];
-        let mut flattened = value.iter().flatten();
-        for i in 0..N * M {
-            data[i] = *flattened.next().unwrap();
-        }
-        Self {
-            data,
-            rows: value.len(),
-            cols: value[0].len(),
-        }
+        Matrix::from_array(std::array::from_fn(|i| rows[i / M][i % M]))
     }
-}
 
-/// Constructors.
-impl<T, const N: usize, const M: usize> Matrix<T, N, M>
-where
-    T: Zero + Copy,
-    [(); N * M]:,
-{
-    /// Initialises a matrix filled with zeroes.
-    ///
-    /// This requires the type of the matrix to implement the num_traits::Zero trait.
-    /// A type implementing NumAssignOps but not Zero is very rare though.
+    /// Assembles a matrix from its columns.
     ///
     /// ## Panics
     ///
-    /// If the specified rows and columns don't create a matrix with N elements.
-    pub fn zeroes(r: usize, c: usize) -> Self {
-        assert_eq!(
-            N, r,
-            "Dimensionality of the matrix does not hold: rows do not match."
-        );
+    /// If there aren't exactly M columns.
+    pub fn from_cols<I: IntoIterator<Item = [T; N]>>(cols: I) -> Self {
+        let cols: Vec<[T; N]> = cols.into_iter().collect();
         assert_eq!(
-            M, c,
-            "Dimensionality of the matrix does not hold: columns do not match."
+            cols.len(),
+            M,
+            "Dimensionality does not hold: specified {} columns but Matrix type is supposed to have {M} columns.",
+            cols.len()
         );
+        Matrix::from_array(std::array::from_fn(|i| cols[i % M][i / M]))
+    }
 
-        Matrix {
-            data: [T::zero(); N * M],
-            rows: r,
-            cols: c,
-        }
+    /// Exports the elements row by row. This is the order they are stored in.
+    pub fn to_row_major(&self) -> Vec<T> {
+        self.data.to_vec()
     }
 
-    /// Constructs a Matrix from a closure. The closure takes in two zero-indexed usizes and
-    /// returns any type T. It requires that T implements Zero for allocation purposes.
-    /// This should probably be changed to implementing Default, now that I think about it.
+    /// Exports the elements column by column, which is the layout expected by
+    /// column-major libraries such as LAPACK.
+    pub fn to_col_major(&self) -> Vec<T> {
+        (0..M)
+            .flat_map(|c| (0..N).map(move |r| self[(r, c)]))
+            .collect()
+    }
+
+    /// Applies a closure to every row and assembles the results into a new matrix.
     ///
     /// ## Example
     ///
     /// ```
     /// use cayley::Matrix;
-    /// let m: Matrix<usize, 2, 3> = Matrix::from_closure(2, 3, |x, y| x + y);
-    /// assert_eq!(m, Matrix::from(vec![vec![0, 1, 2], vec![1, 2, 3]]));
+    /// let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+    /// let reversed = m.map_rows(|row| [row[1], row[0]]);
+    /// assert_eq!(reversed, Matrix::from(vec![vec![2, 1], vec![4, 3]]));
     /// ```
-    pub fn from_closure<F>(r: usize, c: usize, func: F) -> Self
+    pub fn map_rows<F>(&self, func: F) -> Matrix<T, N, M>
     where
-        F: Fn(usize, usize) -> T,
+        F: Fn(&[T]) -> [T; M],
     {
-        let mut result = Matrix::zeroes(r, c);
-        for x in 0..N {
-            for y in 0..M {
-                result[(x, y)] = func(x, y);
-            }
+        // `chunks_exact` can't split the data into N empty rows.
+        if M == 0 {
+            return Matrix::from_rows((0..N).map(|_| func(&[])));
         }
-
-        result
+        Matrix::from_rows(self.data.chunks_exact(M).map(func))
     }
-}
-
-impl<T, const N: usize, const M: usize> Matrix<T, N, M>
-where
-    T: One + Copy,
-    [(); N * M]:,
-{
-    /// Initialises a matrix filled with ones.
-    ///
-    /// This requires the type of the matrix to implement the num_traits::One trait.
-    /// A type implementing NumAssignOps but not One is very rare though.
-    ///
-    /// ## Panics
-    ///
-    /// If the specified rows and columns don't create a matrix with N elements.
-    pub fn ones(r: usize, c: usize) -> Self {
-        assert_eq!(
-            N, r,
-            "Dimensionality of the matrix does not hold: rows do not match."
-        );
-        assert_eq!(
-            M, c,
-            "Dimensionality of the matrix does not hold: columns do not match."
-        );
 
-        Matrix {
-            data: [T::one(); N * M],
-            rows: r,
-            cols: c,
+    /// Applies a closure to every column and assembles the results into a new matrix.
+    pub fn map_cols<F>(&self, func: F) -> Matrix<T, N, M>
+    where
+        F: Fn(&[T]) -> [T; N],
+    {
+        // `col` needs an entry to start from, which a matrix without rows doesn't have.
+        if N == 0 {
+            return Matrix::from_cols((0..M).map(|_| func(&[])));
         }
+        Matrix::from_cols((0..M).map(|c| func(&self.col(c))))
     }
-}
 
-impl<T, const N: usize> Matrix<T, N, N>
-where
-    T: Zero + One + Copy,
-    [(); N * N]:,
-{
-    /// Constructs the identity matrix. Requires the target type to implement
-    /// Zero and One, for obvious reasons.
+    /// Repeats the matrix `R` times vertically and `C` times horizontally.
     ///
     /// ## Example
+    ///
     /// ```
     /// use cayley::Matrix;
-    /// let m: Matrix<i32, 3, 3> = Matrix::identity(3);
-    /// assert_eq!(
-    ///     m,
-    ///     Matrix::from(vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 1]])
-    /// );
+    /// let m: Matrix<i32, 1, 2> = Matrix::from(vec![vec![1, 2]]);
+    /// let t: Matrix<i32, 2, 4> = m.tile::<2, 2>();
+    /// assert_eq!(t, Matrix::from(vec![vec![1, 2, 1, 2], vec![1, 2, 1, 2]]));
     /// ```
-    pub fn identity(size: usize) -> Self {
-        let mut base = Matrix::zeroes(size, size);
-        for i in 0..size {
-            base[(i, i)] = T::one();
+    pub fn tile<const R: usize, const C: usize>(&self) -> Matrix<T, { N * R }, { M * C }>
+    where
+        [(); (N * R) * (M * C)]:,
+    {
+        Matrix {
+            data: std::array::from_fn(|i| {
+                let (x, y) = (i / (M * C), i % (M * C));
+                self[(x % N, y % M)]
+            }),
         }
-        base
     }
-}
 
-// Addition.
-impl<T, Q, const N: usize, const M: usize> Add<Matrix<Q, N, M>> for Matrix<T, N, M>
-where
-    T: Add<Q, Output = T> + Copy,
-    Q: Copy,
-    [(); N * M]:,
-{
-    type Output = Matrix<T, N, M>;
-    fn add(self, rhs: Matrix<Q, N, M>) -> Self::Output {
-        assert_eq!(
-            self.rows, rhs.rows,
-            "Matrices do not have the same dimension."
-        );
-        let mut data: [T; N * M] = self.data;
-        for i in 0..N * M {
-            data[i] = data[i] + rhs.data[i];
-        }
+    /// Mirrors the matrix horizontally, i.e. reverses every row.
+    pub fn flip_horizontal(&self) -> Matrix<T, N, M> {
+        Matrix::from_array(std::array::from_fn(|i| self[(i / M, M - 1 - i % M)]))
+    }
 
-        Matrix {
-            data,
-            rows: self.rows,
-            cols: self.cols,
-        }
+    /// Mirrors the matrix vertically, i.e. reverses the order of the rows.
+    pub fn flip_vertical(&self) -> Matrix<T, N, M> {
+        Matrix::from_array(std::array::from_fn(|i| self[(N - 1 - i / M, i % M)]))
     }
-}
 
-impl<T, Q, const N: usize, const M: usize> AddAssign<Matrix<Q, N, M>> for Matrix<T, N, M>
-where
+    /// Rotates the matrix a quarter turn clockwise. This is the same as transposing
+    /// the matrix and then flipping it horizontally, so the dimensions are swapped.
+    ///
+    /// Rotating a matrix four times returns the original matrix.
+    pub fn rotate90(&self) -> Matrix<T, M, N>
+    where
+        [(); M * N]:,
+    {
+        Matrix::from_array(std::array::from_fn(|i| self[(N - 1 - i % N, i / N)]))
+    }
+
+    /// Cyclically shifts the rows of the matrix down by `n`, so that the last row wraps
+    /// around to the top. A negative `n` shifts the rows up instead.
+    pub fn roll_rows(&self, n: isize) -> Matrix<T, N, M> {
+        if N == 0 {
+            return *self;
+        }
+        let shift = n.rem_euclid(N as isize) as usize;
+        Matrix::from_array(std::array::from_fn(|i| {
+            self[((i / M + N - shift) % N, i % M)]
+        }))
+    }
+
+    /// Cyclically shifts the columns of the matrix right by `n`, so that the last column
+    /// wraps around to the left. A negative `n` shifts the columns left instead.
+    pub fn roll_cols(&self, n: isize) -> Matrix<T, N, M> {
+        if M == 0 {
+            return *self;
+        }
+        let shift = n.rem_euclid(M as isize) as usize;
+        Matrix::from_array(std::array::from_fn(|i| {
+            self[(i / M, (i % M + M - shift) % M)]
+        }))
+    }
+
+    /// Reorders the rows of the matrix: row `i` of the result is row `perm[i]` of the
+    /// original.
+    ///
+    /// ## Panics
+    ///
+    /// If `perm` isn't a permutation of `0..N`.
+    pub fn permute_rows(&self, perm: &[usize; N]) -> Matrix<T, N, M> {
+        assert_permutation(perm);
+        Matrix::from_fn(|x, y| self[(perm[x], y)])
+    }
+
+    /// Reorders the columns of the matrix: column `i` of the result is column `perm[i]`
+    /// of the original.
+    ///
+    /// ## Panics
+    ///
+    /// If `perm` isn't a permutation of `0..M`.
+    pub fn permute_cols(&self, perm: &[usize; M]) -> Matrix<T, N, M> {
+        assert_permutation(perm);
+        Matrix::from_fn(|x, y| self[(x, perm[y])])
+    }
+
+    /// Resizes the matrix to P rows and Q columns. The overlapping top-left region is
+    /// copied over; any new entries are set to `fill`, and entries that don't fit are
+    /// cropped off.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+    /// let r: Matrix<i32, 3, 1> = m.resize(0);
+    /// assert_eq!(r, Matrix::from(vec![vec![1], vec![3], vec![0]]));
+    /// ```
+    pub fn resize<const P: usize, const Q: usize>(&self, fill: T) -> Matrix<T, P, Q>
+    where
+        [(); P * Q]:,
+    {
+        Matrix::from_fn(|x, y| if x < N && y < M { self[(x, y)] } else { fill })
+    }
+
+    /// Picks entries from `self` wherever `mask` is `true`, and from `other` wherever it
+    /// is `false`. Together with the comparison masks this works like an element-wise
+    /// `if`/`else`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<i32, 1, 3> = Matrix::from(vec![vec![-1, 2, -3]]);
+    /// let clamped = m.select(&m.gt(&Matrix::zeroes(1, 3)), &Matrix::zeroes(1, 3));
+    /// assert_eq!(clamped, Matrix::from(vec![vec![0, 2, 0]]));
+    /// ```
+    pub fn select(&self, mask: &Matrix<bool, N, M>, other: &Matrix<T, N, M>) -> Matrix<T, N, M> {
+        Matrix::from_array(std::array::from_fn(|i| {
+            if mask.data[i] {
+                self.data[i]
+            } else {
+                other.data[i]
+            }
+        }))
+    }
+}
+
+// Element-wise comparisons. These return boolean masks, which can be combined with
+// `all` and `any`.
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    T: PartialOrd,
+    [(); N * M]:,
+{
+    /// Compares every entry with the corresponding entry of `other` using `func`.
+    fn compare<F>(&self, other: &Self, func: F) -> Matrix<bool, N, M>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        Matrix::from_array(std::array::from_fn(|i| func(&self.data[i], &other.data[i])))
+    }
+
+    /// Returns a mask which is `true` wherever an entry is greater than the
+    /// corresponding entry of `other`.
+    pub fn gt(&self, other: &Self) -> Matrix<bool, N, M> {
+        self.compare(other, T::gt)
+    }
+
+    /// Returns a mask which is `true` wherever an entry is less than the corresponding
+    /// entry of `other`.
+    pub fn lt(&self, other: &Self) -> Matrix<bool, N, M> {
+        self.compare(other, T::lt)
+    }
+
+    /// Returns a mask which is `true` wherever an entry is greater than or equal to the
+    /// corresponding entry of `other`.
+    pub fn ge(&self, other: &Self) -> Matrix<bool, N, M> {
+        self.compare(other, T::ge)
+    }
+
+    /// Returns a mask which is `true` wherever an entry is less than or equal to the
+    /// corresponding entry of `other`.
+    pub fn le(&self, other: &Self) -> Matrix<bool, N, M> {
+        self.compare(other, T::le)
+    }
+
+    /// Returns a mask which is `true` wherever an entry is equal to the corresponding
+    /// entry of `other`. Not to be confused with `==`, which compares whole matrices.
+    pub fn eq_elem(&self, other: &Self) -> Matrix<bool, N, M> {
+        self.compare(other, T::eq)
+    }
+}
+
+impl<T, const N: usize, const M: usize> From<Vec<Vec<T>>> for Matrix<T, N, M>
+where
+    T: Copy,
+    [(); N * M]:,
+{
+    /// Constructs a matrix from a vector of rows.
+    ///
+    /// ## Panics
+    ///
+    /// If the rows don't all have the same length, or if the number of rows or columns
+    /// doesn't match the Matrix type.
+    fn from(value: Vec<Vec<T>>) -> Matrix<T, N, M> {
+        assert!(
+            value.iter().all(|row| row.len() == value[0].len()),
+            "Not all rows have the same length."
+        );
+        assert_eq!(value.len(), N, "Dimensionality does not hold: specified a vector with {} elements but Matrix type is supposed to have {N} rows.", value.len());
+        assert_eq!(value[0].len(), M, "Dimensionality does not hold: specified a vector whose elements have {} elements but Matrix type is supposed to have {M} columns.", value[0].len());
+
+        let mut data = [value[0][0]; N * M];
+        for (slot, &e) in data.iter_mut().zip(value.iter().flatten()) {
+            *slot = e;
+        }
+        Self { data }
+    }
+}
+
+impl<T, const N: usize, const M: usize> From<Vec<T>> for Matrix<T, N, M>
+where
+    T: Copy,
+    [(); N * M]:,
+{
+    /// Constructs a matrix from a flat vector, whose elements are in 'reading order'
+    /// (the same order as the `data` field).
+    ///
+    /// ## Panics
+    ///
+    /// If the vector doesn't contain exactly N * M elements.
+    fn from(value: Vec<T>) -> Matrix<T, N, M> {
+        assert_eq!(value.len(), N * M, "Dimensionality does not hold: specified a vector with {} elements but Matrix type is supposed to have {} elements.", value.len(), N * M);
+
+        let mut elements = value.into_iter();
+        Matrix::from_array(std::array::from_fn(|_| elements.next().unwrap()))
+    }
+}
+
+impl<T> From<T> for Matrix<T, 1, 1> {
+    /// Wraps a single value in a 1×1 matrix.
+    fn from(value: T) -> Matrix<T, 1, 1> {
+        Matrix::from_array([value])
+    }
+}
+
+impl<T, const N: usize, const M: usize> FromIterator<T> for Matrix<T, N, M>
+where
+    [(); N * M]:,
+{
+    /// Collects exactly N * M elements in 'reading order' into a matrix.
+    ///
+    /// ## Panics
+    ///
+    /// If the iterator yields too few or too many elements.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let data = std::array::from_fn(|i| {
+            iter.next().unwrap_or_else(|| panic!("Dimensionality does not hold: iterator yielded {i} elements but Matrix type is supposed to have {} elements.", N * M))
+        });
+        assert!(
+            iter.next().is_none(),
+            "Dimensionality does not hold: iterator yielded more than the {} elements the Matrix type is supposed to have.",
+            N * M
+        );
+
+        Matrix::from_array(data)
+    }
+}
+
+/// Constructors.
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    T: Zero + Copy,
+    [(); N * M]:,
+{
+    /// Initialises a matrix filled with zeroes.
+    ///
+    /// This requires the type of the matrix to implement the num_traits::Zero trait.
+    /// A type implementing NumAssignOps but not Zero is very rare though.
+    ///
+    /// ## Panics
+    ///
+    /// If the specified rows and columns don't create a matrix with N elements.
+    pub fn zeroes(r: usize, c: usize) -> Self {
+        assert_eq!(
+            N, r,
+            "Dimensionality of the matrix does not hold: rows do not match."
+        );
+        assert_eq!(
+            M, c,
+            "Dimensionality of the matrix does not hold: columns do not match."
+        );
+
+        Matrix {
+            data: [T::zero(); N * M],
+        }
+    }
+
+    /// Constructs a Matrix from a closure. The closure takes in two zero-indexed usizes and
+    /// returns any type T. This is the same as `from_fn`, except that the dimensions are
+    /// checked against the ones given.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<usize, 2, 3> = Matrix::from_closure(2, 3, |x, y| x + y);
+    /// assert_eq!(m, Matrix::from(vec![vec![0, 1, 2], vec![1, 2, 3]]));
+    /// ```
+    pub fn from_closure<F>(r: usize, c: usize, func: F) -> Self
+    where
+        F: Fn(usize, usize) -> T,
+    {
+        assert_eq!(
+            N, r,
+            "Dimensionality of the matrix does not hold: rows do not match."
+        );
+        assert_eq!(
+            M, c,
+            "Dimensionality of the matrix does not hold: columns do not match."
+        );
+
+        Self::from_fn(func)
+    }
+
+    /// Calculates the direct sum of two matrices: the block diagonal matrix with `self`
+    /// in the top left and `other` in the bottom right, and zeroes everywhere else.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let a: Matrix<i32, 1, 2> = Matrix::from(vec![vec![1, 2]]);
+    /// let b: Matrix<i32, 1, 1> = Matrix::from(vec![vec![3]]);
+    /// assert_eq!(a.direct_sum(&b), Matrix::from(vec![vec![1, 2, 0], vec![0, 0, 3]]));
+    /// ```
+    pub fn direct_sum<const O: usize, const P: usize>(
+        &self,
+        other: &Matrix<T, O, P>,
+    ) -> Matrix<T, { N + O }, { M + P }>
+    where
+        [(); O * P]:,
+        [(); (N + O) * (M + P)]:,
+    {
+        Matrix::from_fn(|x, y| {
+            if x < N && y < M {
+                self[(x, y)]
+            } else if x >= N && y >= M {
+                other[(x - N, y - M)]
+            } else {
+                T::zero()
+            }
+        })
+    }
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    T: One + Copy,
+    [(); N * M]:,
+{
+    /// Initialises a matrix filled with ones.
+    ///
+    /// This requires the type of the matrix to implement the num_traits::One trait.
+    /// A type implementing NumAssignOps but not One is very rare though.
+    ///
+    /// ## Panics
+    ///
+    /// If the specified rows and columns don't create a matrix with N elements.
+    pub fn ones(r: usize, c: usize) -> Self {
+        assert_eq!(
+            N, r,
+            "Dimensionality of the matrix does not hold: rows do not match."
+        );
+        assert_eq!(
+            M, c,
+            "Dimensionality of the matrix does not hold: columns do not match."
+        );
+
+        Matrix {
+            data: [T::one(); N * M],
+        }
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Zero + One + Copy,
+    [(); N * N]:,
+{
+    /// Constructs the identity matrix. Requires the target type to implement
+    /// Zero and One, for obvious reasons.
+    ///
+    /// ## Example
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<i32, 3, 3> = Matrix::identity(3);
+    /// assert_eq!(
+    ///     m,
+    ///     Matrix::from(vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 1]])
+    /// );
+    /// ```
+    pub fn identity(size: usize) -> Self {
+        let mut base = Matrix::zeroes(size, size);
+        for i in 0..size {
+            base[(i, i)] = T::one();
+        }
+        base
+    }
+
+    /// Constructs a homogeneous scaling matrix. The matrix is one bigger than the number of
+    /// factors given: the factors are put on the diagonal, and the bottom-right corner is one.
+    ///
+    /// ## Example
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<i32, 3, 3> = Matrix::scaling(&[2, 3]);
+    /// assert_eq!(
+    ///     m,
+    ///     Matrix::from(vec![vec![2, 0, 0], vec![0, 3, 0], vec![0, 0, 1]])
+    /// );
+    /// ```
+    pub fn scaling<const K: usize>(factors: &[T; K]) -> Self
+    where
+        DimensionAssertion<{ K + 1 == N }>: IsTrue,
+    {
+        let mut base = Matrix::identity(N);
+        for i in 0..K {
+            base[(i, i)] = factors[i];
+        }
+        base
+    }
+
+    /// Constructs a homogeneous translation matrix: the identity matrix with the offsets
+    /// put in the last column. Like with `scaling`, the matrix is one bigger than the
+    /// number of offsets given.
+    ///
+    /// ## Example
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<i32, 3, 3> = Matrix::translation(&[4, 5]);
+    /// assert_eq!(
+    ///     m,
+    ///     Matrix::from(vec![vec![1, 0, 4], vec![0, 1, 5], vec![0, 0, 1]])
+    /// );
+    /// ```
+    pub fn translation<const K: usize>(offsets: &[T; K]) -> Self
+    where
+        DimensionAssertion<{ K + 1 == N }>: IsTrue,
+    {
+        let mut base = Matrix::identity(N);
+        for i in 0..K {
+            base[(i, K)] = offsets[i];
+        }
+        base
+    }
+
+    /// Constructs the companion matrix of the monic polynomial
+    /// `xᴺ + cₙ₋₁xᴺ⁻¹ + ... + c₁x + c₀`, whose eigenvalues are exactly the roots of the
+    /// polynomial. The coefficients are given in ascending order, i.e. `[c₀, c₁, ..., cₙ₋₁]`;
+    /// the leading coefficient is an implicit one.
+    ///
+    /// The matrix has ones on the superdiagonal and the negated coefficients on the
+    /// bottom row.
+    ///
+    /// ## Example
+    /// ```
+    /// use cayley::Matrix;
+    /// // x² - 5x + 6
+    /// let m: Matrix<i32, 2, 2> = Matrix::companion(&[6, -5]);
+    /// assert_eq!(m, Matrix::from(vec![vec![0, 1], vec![-6, 5]]));
+    /// ```
+    pub fn companion(coefficients: &[T; N]) -> Self
+    where
+        T: Neg<Output = T>,
+    {
+        Matrix::from_fn(|x, y| {
+            if x + 1 == N {
+                -coefficients[y]
+            } else if y == x + 1 {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Zero + Copy,
+    [(); N * N]:,
+{
+    /// Constructs a tridiagonal matrix from its sub-diagonal, main diagonal and
+    /// super-diagonal. All other entries are zero.
+    ///
+    /// ## Example
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<i32, 3, 3> = Matrix::tridiagonal(&[1, 2], &[3, 4, 5], &[6, 7]);
+    /// assert_eq!(
+    ///     m,
+    ///     Matrix::from(vec![vec![3, 6, 0], vec![1, 4, 7], vec![0, 2, 5]])
+    /// );
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// If `diag` doesn't have N elements, or `sub` and `sup` don't have N - 1 elements.
+    pub fn tridiagonal(sub: &[T], diag: &[T], sup: &[T]) -> Self {
+        assert_eq!(
+            diag.len(),
+            N,
+            "Dimensionality does not hold: the main diagonal should have {N} elements."
+        );
+        let off_diagonal = N.saturating_sub(1);
+        assert!(
+            sub.len() == off_diagonal && sup.len() == off_diagonal,
+            "Dimensionality does not hold: the sub- and super-diagonals should have {off_diagonal} elements."
+        );
+
+        Matrix::from_fn(|x, y| {
+            if x == y {
+                diag[x]
+            } else if x == y + 1 {
+                sub[y]
+            } else if y == x + 1 {
+                sup[x]
+            } else {
+                T::zero()
+            }
+        })
+    }
+}
+
+impl<T, const N: usize, const M: usize> Zero for Matrix<T, N, M>
+where
+    T: Zero + Copy,
+    [(); N * M]:,
+{
+    /// Returns the zero matrix, so that matrices can be used in code that is generic
+    /// over `num_traits::Zero`.
+    fn zero() -> Self {
+        Matrix::zeroes(N, M)
+    }
+
+    /// Checks if every entry is zero.
+    fn is_zero(&self) -> bool {
+        self.all(|e| e.is_zero())
+    }
+}
+
+#[allow(clippy::eq_op)]
+impl<T, const N: usize> One for Matrix<T, N, N>
+where
+    T: Zero + One + Copy,
+    [(); N * N]:,
+    DimensionAssertion<{ N == N }>: IsTrue,
+{
+    /// Returns the identity matrix, so that square matrices can be used in code that is
+    /// generic over `num_traits::One`.
+    fn one() -> Self {
+        Matrix::identity(N)
+    }
+}
+
+// Addition.
+impl<T, Q, const N: usize, const M: usize> Add<Matrix<Q, N, M>> for Matrix<T, N, M>
+where
+    T: Add<Q, Output = T> + Copy,
+    Q: Copy,
+    [(); N * M]:,
+{
+    type Output = Matrix<T, N, M>;
+    fn add(self, rhs: Matrix<Q, N, M>) -> Self::Output {
+        let mut data = self.data;
+        for (e, &r) in data.iter_mut().zip(rhs.data.iter()) {
+            *e = *e + r;
+        }
+
+        Matrix { data }
+    }
+}
+
+impl<T, Q, const N: usize, const M: usize> AddAssign<Matrix<Q, N, M>> for Matrix<T, N, M>
+where
     T: AddAssign<Q>,
     Q: Copy,
     [(); N * M]:,
 {
-    fn add_assign(&mut self, rhs: Matrix<Q, N, M>) {
+    fn add_assign(&mut self, rhs: Matrix<Q, N, M>) {
+        for i in 0..N * M {
+            self.data[i] += rhs.data[i];
+        }
+    }
+}
+
+impl<T, Q, const N: usize, const M: usize> Add<&Matrix<Q, N, M>> for &Matrix<T, N, M>
+where
+    T: Add<Q, Output = T> + Copy,
+    Q: Copy,
+    [(); N * M]:,
+{
+    type Output = Matrix<T, N, M>;
+    /// Adds two matrices without consuming either of them.
+    fn add(self, rhs: &Matrix<Q, N, M>) -> Self::Output {
+        Matrix::from_array(std::array::from_fn(|i| self.data[i] + rhs.data[i]))
+    }
+}
+
+impl<T, Q, const N: usize, const M: usize> AddAssign<&Matrix<Q, N, M>> for Matrix<T, N, M>
+where
+    T: AddAssign<Q>,
+    Q: Copy,
+    [(); N * M]:,
+{
+    fn add_assign(&mut self, rhs: &Matrix<Q, N, M>) {
+        for i in 0..N * M {
+            self.data[i] += rhs.data[i];
+        }
+    }
+}
+
+// Subtraction.
+impl<T, Q, const N: usize, const M: usize> Sub<Matrix<Q, N, M>> for Matrix<T, N, M>
+where
+    T: Sub<Q, Output = T> + Copy,
+    Q: Copy,
+    [(); N * M]:,
+{
+    type Output = Matrix<T, N, M>;
+    fn sub(self, rhs: Matrix<Q, N, M>) -> Self::Output {
+        let mut data = self.data;
+        for (e, &r) in data.iter_mut().zip(rhs.data.iter()) {
+            *e = *e - r;
+        }
+
+        Matrix { data }
+    }
+}
+
+impl<T, Q, const N: usize, const M: usize> SubAssign<Matrix<Q, N, M>> for Matrix<T, N, M>
+where
+    T: SubAssign<Q>,
+    Q: Copy,
+    [(); N * M]:,
+{
+    fn sub_assign(&mut self, rhs: Matrix<Q, N, M>) {
+        for i in 0..N * M {
+            self.data[i] -= rhs.data[i];
+        }
+    }
+}
+
+// Scalar arithmetic. A blanket `impl<T> Add<T> for Matrix<T, N, M>` would overlap with
+// the matrix-matrix impls above (T could itself be a matrix), so these are implemented
+// for the primitive numeric types only.
+macro_rules! impl_scalar_ops {
+    ($($t:ty),*) => {
+        $(
+            impl<const N: usize, const M: usize> Add<$t> for Matrix<$t, N, M>
+            where
+                [(); N * M]:,
+            {
+                type Output = Matrix<$t, N, M>;
+                /// Adds a scalar to every entry of the matrix.
+                fn add(self, rhs: $t) -> Self::Output {
+                    Matrix::from_array(std::array::from_fn(|i| self.data[i] + rhs))
+                }
+            }
+
+            impl<const N: usize, const M: usize> Sub<$t> for Matrix<$t, N, M>
+            where
+                [(); N * M]:,
+            {
+                type Output = Matrix<$t, N, M>;
+                /// Subtracts a scalar from every entry of the matrix.
+                fn sub(self, rhs: $t) -> Self::Output {
+                    Matrix::from_array(std::array::from_fn(|i| self.data[i] - rhs))
+                }
+            }
+
+            impl<const N: usize, const M: usize> Mul<$t> for Matrix<$t, N, M>
+            where
+                [(); N * M]:,
+            {
+                type Output = Matrix<$t, N, M>;
+                /// Multiplies every entry of the matrix by a scalar.
+                fn mul(self, rhs: $t) -> Self::Output {
+                    self.scale(rhs)
+                }
+            }
+
+            impl<const N: usize, const M: usize> Div<$t> for Matrix<$t, N, M>
+            where
+                [(); N * M]:,
+            {
+                type Output = Matrix<$t, N, M>;
+                /// Divides every entry of the matrix by a scalar. For integer types the
+                /// division rounds towards zero.
+                fn div(self, rhs: $t) -> Self::Output {
+                    self.unscale(rhs)
+                }
+            }
+
+            impl<const N: usize, const M: usize> AddAssign<$t> for Matrix<$t, N, M>
+            where
+                [(); N * M]:,
+            {
+                /// Adds a scalar to every entry of the matrix in place.
+                fn add_assign(&mut self, rhs: $t) {
+                    for e in self.data.iter_mut() {
+                        *e += rhs;
+                    }
+                }
+            }
+
+            impl<const N: usize, const M: usize> SubAssign<$t> for Matrix<$t, N, M>
+            where
+                [(); N * M]:,
+            {
+                /// Subtracts a scalar from every entry of the matrix in place.
+                fn sub_assign(&mut self, rhs: $t) {
+                    for e in self.data.iter_mut() {
+                        *e -= rhs;
+                    }
+                }
+            }
+
+            impl<const N: usize, const M: usize> MulAssign<$t> for Matrix<$t, N, M>
+            where
+                [(); N * M]:,
+            {
+                /// Multiplies every entry of the matrix by a scalar in place.
+                fn mul_assign(&mut self, rhs: $t) {
+                    for e in self.data.iter_mut() {
+                        *e *= rhs;
+                    }
+                }
+            }
+
+            impl<const N: usize, const M: usize> DivAssign<$t> for Matrix<$t, N, M>
+            where
+                [(); N * M]:,
+            {
+                /// Divides every entry of the matrix by a scalar in place. For integer
+                /// types the division rounds towards zero.
+                fn div_assign(&mut self, rhs: $t) {
+                    for e in self.data.iter_mut() {
+                        *e /= rhs;
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_ops!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// Scalar multiplication with the scalar on the left. This is only implemented for the
+// floating-point types: for the integer types, the compiler would have to consider these
+// impls while inferring the type of const expressions like `N * M` and `1 * N` in the
+// bounds, which it can't do without running into a cycle.
+macro_rules! impl_scalar_lhs_mul {
+    ($($t:ty),*) => {
+        $(
+            impl<const N: usize, const M: usize> Mul<Matrix<$t, N, M>> for $t
+            where
+                [(); N * M]:,
+            {
+                type Output = Matrix<$t, N, M>;
+                /// Multiplies every entry of the matrix by a scalar.
+                fn mul(self, rhs: Matrix<$t, N, M>) -> Self::Output {
+                    rhs.scale(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_lhs_mul!(f32, f64);
+
+// Negation
+
+impl<T, const N: usize, const M: usize> Neg for Matrix<T, N, M>
+where
+    T: Neg<Output = T>,
+    [(); N * M]:,
+{
+    type Output = Self;
+    /// Negates every entry of the matrix.
+    fn neg(self) -> Self::Output {
+        Matrix::from_array(self.data.map(T::neg))
+    }
+}
+
+// Multiplication
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    T: Copy + Mul<Output = T> + Zero,
+    [(); N * M]:,
+{
+    /// Multiplies every element of a Matrix with a scalar value. This is an alias for
+    /// `scale`.
+    pub fn scalar_mul(&self, rhs: T) -> Matrix<T, N, M> {
+        self.scale(rhs)
+    }
+
+    /// Multiplies the matrix with a column vector. This gives the same result as `*`,
+    /// but accumulates every entry directly instead of going through a scratch array.
+    #[allow(clippy::identity_op)]
+    pub fn mul_vec(&self, v: &Matrix<T, M, 1>) -> Matrix<T, N, 1>
+    where
+        [(); M * 1]:,
+        [(); N * 1]:,
+    {
+        let mut result: Matrix<T, N, 1> = Matrix::zeroes(N, 1);
+        for x in 0..N {
+            for i in 0..M {
+                result.data[x] = result.data[x] + self[(x, i)] * v.data[i];
+            }
+        }
+        result
+    }
+
+    /// Calculates the residual `b - Ax` of a candidate solution `x` of the system
+    /// `Ax = b`. This is zero exactly when `x` solves the system.
+    #[allow(clippy::identity_op)]
+    pub fn residual(&self, x: &Matrix<T, M, 1>, b: &Matrix<T, N, 1>) -> Matrix<T, N, 1>
+    where
+        T: Sub<Output = T>,
+        [(); M * 1]:,
+        [(); N * 1]:,
+    {
+        *b - self.mul_vec(x)
+    }
+
+    /// Calculates the Euclidean norm of the residual `b - Ax`, as a single measure of
+    /// how well `x` solves the system `Ax = b`.
+    #[allow(clippy::identity_op)]
+    pub fn residual_norm(&self, x: &Matrix<T, M, 1>, b: &Matrix<T, N, 1>) -> T
+    where
+        T: Float,
+        [(); M * 1]:,
+        [(); N * 1]:,
+    {
+        self.residual(x, b).norm_frobenius()
+    }
+
+    /// Performs the rank-1 update `A += α·uv` in place, where `uv` is the outer product
+    /// of a column vector and a row vector. This is the BLAS `ger` operation; every entry
+    /// is updated directly, so the outer product is never stored as a separate matrix.
+    #[allow(clippy::identity_op)]
+    pub fn rank1_update(&mut self, u: &Matrix<T, N, 1>, v: &Matrix<T, 1, M>, alpha: T)
+    where
+        [(); N * 1]:,
+        [(); 1 * M]:,
+    {
+        for x in 0..N {
+            let scaled = alpha * u.data[x];
+            for y in 0..M {
+                self[(x, y)] = self[(x, y)] + scaled * v.data[y];
+            }
+        }
+    }
+
+    /// Evaluates the bilinear form `xᵀAy`. The sum `Σ xᵢ aᵢⱼ yⱼ` is accumulated directly,
+    /// so no intermediate vectors or 1×1 matrices are created.
+    #[allow(clippy::identity_op)]
+    pub fn bilinear_form(&self, x: &Matrix<T, N, 1>, y: &Matrix<T, M, 1>) -> T
+    where
+        [(); N * 1]:,
+        [(); M * 1]:,
+    {
+        let mut sum = T::zero();
+        for i in 0..N {
+            for j in 0..M {
+                sum = sum + x.data[i] * self[(i, j)] * y.data[j];
+            }
+        }
+        sum
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Copy + Mul<Output = T> + Zero,
+    [(); N * N]:,
+{
+    /// Evaluates the quadratic form `xᵀAx`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let a: Matrix<i32, 2, 2> = Matrix::from(vec![vec![2, 1], vec![1, 3]]);
+    /// let x: Matrix<i32, 2, 1> = Matrix::from(vec![1, -1]);
+    /// assert_eq!(a.quadratic_form(&x), 3);
+    /// ```
+    #[allow(clippy::identity_op)]
+    pub fn quadratic_form(&self, x: &Matrix<T, N, 1>) -> T
+    where
+        [(); N * 1]:,
+    {
+        self.bilinear_form(x, x)
+    }
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    T: Copy,
+    [(); N * M]:,
+{
+    /// Multiplies every entry of the matrix by `factor`. For the primitive numeric types
+    /// this is also available as `m * factor`, and for floats as `factor * m` as well.
+    /// `scalar_mul` does the same, but additionally requires `T: Zero`.
+    pub fn scale(&self, factor: T) -> Matrix<T, N, M>
+    where
+        T: Mul<Output = T>,
+    {
+        Matrix::from_array(self.data.map(|e| e * factor))
+    }
+
+    /// Divides every entry of the matrix by `divisor`. For the primitive numeric types
+    /// this is also available as `m / divisor`.
+    pub fn unscale(&self, divisor: T) -> Matrix<T, N, M>
+    where
+        T: Div<Output = T>,
+    {
+        Matrix::from_array(self.data.map(|e| e / divisor))
+    }
+
+    /// Multiplies two matrices of the same dimensions entry by entry. This is also known
+    /// as the Hadamard product.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<i32, 1, 3> = Matrix::from(vec![1, 2, 3]);
+    /// let weights: Matrix<i32, 1, 3> = Matrix::from(vec![0, 1, 2]);
+    /// assert_eq!(m.component_mul(&weights), Matrix::from(vec![0, 2, 6]));
+    /// ```
+    pub fn component_mul<Q, R>(&self, rhs: &Matrix<Q, N, M>) -> Matrix<R, N, M>
+    where
+        T: Mul<Q, Output = R>,
+        Q: Copy,
+    {
+        Matrix::from_array(std::array::from_fn(|i| self.data[i] * rhs.data[i]))
+    }
+
+    /// Divides two matrices of the same dimensions entry by entry.
+    pub fn component_div<Q, R>(&self, rhs: &Matrix<Q, N, M>) -> Matrix<R, N, M>
+    where
+        T: Div<Q, Output = R>,
+        Q: Copy,
+    {
+        Matrix::from_array(std::array::from_fn(|i| self.data[i] / rhs.data[i]))
+    }
+}
+
+impl<T, Q, R, const N: usize, const M: usize, const O: usize, const P: usize> Mul<Matrix<Q, O, P>>
+    for Matrix<T, N, M>
+where
+    T: Copy + Mul<Q, Output = R>,
+    Q: Copy,
+    R: Add + Zero + Copy,
+    [(); N * M]:,
+    [(); O * P]:,
+    [(); N * P]:,
+    DimensionAssertion<{ M == O }>: IsTrue,
+{
+    type Output = Matrix<R, N, P>;
+    /// Multiplies two matrices.
+    ///
+    /// ## Examples
+    ///
+    /// ```compile_fail
+    /// let m1: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// let m2: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+    /// let a = m1 * m2; // this does not compile!
+    /// ```
+    fn mul(self, rhs: Matrix<Q, O, P>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<T, Q, R, const N: usize, const M: usize, const O: usize, const P: usize> Mul<&Matrix<Q, O, P>>
+    for &Matrix<T, N, M>
+where
+    T: Copy + Mul<Q, Output = R>,
+    Q: Copy,
+    R: Add + Zero + Copy,
+    [(); N * M]:,
+    [(); O * P]:,
+    [(); N * P]:,
+    DimensionAssertion<{ M == O }>: IsTrue,
+{
+    type Output = Matrix<R, N, P>;
+    /// Multiplies two matrices without consuming either of them.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: &Matrix<Q, O, P>) -> Self::Output {
+        let mut result: Matrix<R, N, P> = Matrix::zeroes(N, P);
+
+        // The dot product is accumulated into a running sum, rather than first collecting
+        // all of its terms into a scratch array, so no temporary array is needed per entry.
+        // The terms are still added up in the same order, so the result is identical.
+        for x in 0..N {
+            for y in 0..P {
+                let mut dot_product = R::zero();
+                for i in 0..M {
+                    dot_product = dot_product + self[(x, i)] * rhs[(i, y)];
+                }
+                result[(x, y)] = dot_product;
+            }
+        }
+
+        result
+    }
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    T: Copy,
+    [(); N * M]:,
+{
+    /// Calculates the Frobenius inner product of two matrices of the same dimensions:
+    /// the sum of the products of corresponding elements. This is equal to `trace(AᵀB)`,
+    /// but doesn't need to calculate the full matrix product.
+    pub fn frobenius_inner<Q, R>(&self, other: &Matrix<Q, N, M>) -> R
+    where
+        T: Mul<Q, Output = R>,
+        Q: Copy,
+        R: Zero,
+    {
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .fold(R::zero(), |acc, (a, b)| acc + *a * *b)
+    }
+
+    /// Calculates the Kronecker product `A ⊗ B`: the block matrix in which block `(i, j)`
+    /// is `aᵢⱼB`. Its dimensions are known at compile time.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let a: Matrix<i32, 1, 2> = Matrix::from(vec![vec![1, 2]]);
+    /// let b: Matrix<i32, 2, 1> = Matrix::from(vec![vec![3], vec![4]]);
+    /// let k: Matrix<i32, 2, 2> = a.kronecker(&b);
+    /// assert_eq!(k, Matrix::from(vec![vec![3, 6], vec![4, 8]]));
+    /// ```
+    pub fn kronecker<Q, R, const O: usize, const P: usize>(
+        &self,
+        rhs: &Matrix<Q, O, P>,
+    ) -> Matrix<R, { N * O }, { M * P }>
+    where
+        T: Mul<Q, Output = R>,
+        Q: Copy,
+        [(); O * P]:,
+        [(); (N * O) * (M * P)]:,
+    {
+        Matrix::from_fn(|x, y| self[(x / O, y / P)] * rhs[(x % O, y % P)])
+    }
+
+    /// Calculates `trace(AB)` without calculating the full matrix product, by summing
+    /// `a_ij * b_ji` directly. This takes O(NM) operations instead of O(N²M).
+    pub fn trace_of_product<Q, R>(&self, other: &Matrix<Q, M, N>) -> R
+    where
+        T: Mul<Q, Output = R>,
+        Q: Copy,
+        R: Zero,
+        [(); M * N]:,
+    {
+        let mut sum = R::zero();
+        for i in 0..N {
+            for j in 0..M {
+                sum = sum + self[(i, j)] * other[(j, i)];
+            }
+        }
+        sum
+    }
+}
+
+#[allow(clippy::eq_op)]
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Copy + Zero + Mul<Output = T>,
+    [(); N * N]:,
+    DimensionAssertion<{ N == N }>: IsTrue,
+{
+    /// Calculates the commutator `[A, B] = AB - BA`, which is zero exactly when the two
+    /// matrices commute. Since `AB` and `BA` have the same trace, the commutator is
+    /// always traceless.
+    pub fn commutator(&self, other: &Self) -> Matrix<T, N, N>
+    where
+        T: Sub<Output = T>,
+    {
+        self * other - other * self
+    }
+
+    /// Calculates the anticommutator `{A, B} = AB + BA`.
+    pub fn anticommutator(&self, other: &Self) -> Matrix<T, N, N> {
+        self * other + other * self
+    }
+
+    /// Raises the matrix to a nonnegative integer power by repeated squaring, which takes
+    /// O(log exp) matrix multiplications instead of `exp - 1`. `A⁰` is the identity.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// // powers of this matrix contain the Fibonacci numbers
+    /// let m: Matrix<u64, 2, 2> = Matrix::from(vec![vec![1, 1], vec![1, 0]]);
+    /// assert_eq!(m.pow(10)[(0, 1)], 55);
+    /// ```
+    pub fn pow(&self, mut exp: u32) -> Matrix<T, N, N>
+    where
+        T: One,
+    {
+        let mut result: Matrix<T, N, N> =
+            Matrix::from_fn(|x, y| if x == y { T::one() } else { T::zero() });
+        let mut base = *self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            exp >>= 1;
+            // Skipping the last squaring keeps integer matrices from overflowing needlessly.
+            if exp > 0 {
+                base = base * base;
+            }
+        }
+        result
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Copy + Zero,
+    [(); N * N]:,
+{
+    /// Calculates the trace of a square matrix, i.e. the sum of the elements on
+    /// its main diagonal. The trace of the empty matrix is zero.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 7], vec![-3, 4]]);
+    /// assert_eq!(m.trace(), 5);
+    /// ```
+    pub fn trace(&self) -> T {
+        (0..N).fold(T::zero(), |acc, i| acc + self[(i, i)])
+    }
+
+    /// Calculates the partial trace of a matrix, viewed as the Kronecker product `A ⊗ B`
+    /// of a square matrix `A` of size N / D and a square matrix `B` of size D. The
+    /// factor `B` is traced out, leaving a matrix of the same size as `A`.
+    ///
+    /// N has to be divisible by D; this is checked at compile time.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<i32, 4, 4> = Matrix::identity(4);
+    /// let reduced: Matrix<i32, 2, 2> = m.partial_trace::<2>();
+    /// assert_eq!(reduced, Matrix::from(vec![vec![2, 0], vec![0, 2]]));
+    /// ```
+    pub fn partial_trace<const D: usize>(&self) -> Matrix<T, { N / D }, { N / D }>
+    where
+        [(); (N / D) * (N / D)]:,
+        DimensionAssertion<{ N.is_multiple_of(D) }>: IsTrue,
+    {
+        let mut result = Matrix::zeroes(N / D, N / D);
+        for x in 0..N / D {
+            for y in 0..N / D {
+                for k in 0..D {
+                    result[(x, y)] = result[(x, y)] + self[(x * D + k, y * D + k)];
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float,
+    [(); N * N]:,
+{
+    /// Divides the matrix by its trace, so that the result has trace one. This is the
+    /// usual normalisation for density matrices.
+    ///
+    /// Returns `None` if the trace is zero, since there is nothing sensible to divide by.
+    pub fn trace_normalize(&self) -> Option<Matrix<T, N, N>> {
+        let trace = self.trace();
+        if trace.is_zero() {
+            None
+        } else {
+            Some(Matrix::from_array(std::array::from_fn(|i| {
+                self.data[i] / trace
+            })))
+        }
+    }
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    [(); N * M]:,
+    [(); M * N]:,
+    T: Copy + Zero,
+{
+    /// Returns the transpose of the matrix.
+    ///
+    /// Note that this means that (unless the Matrix is square) the return type is
+    /// different from the caller type: `Matrix<u8, 2, 3>.transpose()` returns a `Matrix<u8, 3, 2>`.
+    pub fn transpose(&self) -> Matrix<T, M, N> {
+        let mut result = Matrix::zeroes(M, N);
+
+        for x in 0..M {
+            for y in 0..N {
+                result[(x, y)] = self[(y, x)];
+            }
+        }
+
+        result
+    }
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    [(); N * M]:,
+    [(); (N - 1) * (M - 1)]:,
+    T: Zero + Copy,
+{
+    /// Calculates the submatrix of a matrix. The submatrix is the smaller matrix aqcuired from
+    /// ignoring the existence of one row and one column from that matrix.
+    pub fn submatrix(&self, r: usize, c: usize) -> Matrix<T, { N - 1 }, { M - 1 }> {
+        assert!(r < N, "Specified out-of-bounds index in creating a submatrix: indexed row {} while matrix has {} rows.", r, N);
+        assert!(c < M, "Specified out-of-bounds index in creating a submatrix: indexed column {} while matrix has {} columns.", c, M);
+
+        let mut subm: Matrix<T, { N - 1 }, { M - 1 }> = Matrix::zeroes(N - 1, M - 1);
+
+        let mut x_counter = 0usize;
+        let mut y_counter = 0usize;
+
+        for x in 0..N {
+            if x == r {
+                continue;
+            }
+            for y in 0..M {
+                if y == c {
+                    continue;
+                }
+                subm[(x_counter, y_counter)] = self[(x, y)];
+                y_counter += 1;
+            }
+            y_counter = 0;
+            x_counter += 1;
+        }
+
+        subm
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    [(); N * N]:,
+    [(); (N - 1) * (N - 1)]:,
+    T: Zero + Copy,
+{
+    /// Calculates the submatrix of a square matrix. This implementation is necessary for some
+    /// other calculations to be valid (e.g. the comatrix); it does not perform better at all.
+    pub fn square_submatrix(&self, r: usize, c: usize) -> Matrix<T, { N - 1 }, { N - 1 }> {
+        self.submatrix(r, c)
+    }
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    [(); N * M]:,
+    T: Zero + One + Copy + NumOps + PartialEq + PartialOrd + Signed,
+{
+    /// Applies an elementary row operation to a specified row.
+    ///
+    /// This function takes a source row index and a target row index, as
+    /// well as a closure that itself takes two inputs. These two inputs act
+    /// as a placeholder for an element of the source and target row respectively
+    /// in an expression describing what happens to each element of the target row.
+    /// For example, the operation "add two times the first row to the fifth row" would
+    /// look like `matrix.row_op(0, 4, |r1, r2| 2 * r1 + r2)`.
+    ///
+    /// Note that this makes `row_op` quite a powerful function: a row effectively be
+    /// copied to another one, or set to a custom value.
+    pub fn row_op<F>(&mut self, source_row: usize, target_row: usize, func: F)
+    where
+        F: Fn(T, T) -> T,
+    {
+        assert!(
+            source_row < N,
+            "Index out of bounds: tried to access row {source_row} but matrix has {N} rows."
+        );
+        assert!(
+            target_row < N,
+            "Index out of bounds: tried to access row {target_row} but matrix has {N} rows."
+        );
+        for i in 0..M {
+            self[(target_row, i)] = func(self[(source_row, i)], self[(target_row, i)]);
+        }
+    }
+
+    /// Swaps two rows. Mutates the matrix in-place.
+    pub fn row_swap(&mut self, r1: usize, r2: usize) {
+        assert!(
+            r1 < N,
+            "Index out of bounds: tried to access row {r1} but matrix has {N} rows."
+        );
+        assert!(
+            r2 < N,
+            "Index out of bounds: tried to access row {r2} but matrix has {N} rows."
+        );
+
+        let tmp = self.row(r1);
+        for i in 0..M {
+            self[(r1, i)] = self[(r2, i)];
+            self[(r2, i)] = tmp[i];
+        }
+    }
+
+    /// Mutates a matrix to be in row echelon form. The naming
+    /// convention here is slightly annoying as "row echelon form" would
+    /// be shortened to 'ref', which is obviously a reserved Rust keyword.
+    ///
+    /// Unlike most methods implemented on `Matrix`, this mutates `self` without
+    /// returning a new matrix. What it does return, however, is a product of all
+    /// row operation factors. This is used to calculate the determinant.
+    pub fn row_ef(&mut self) -> T {
+        // the accumulating factor
+        let mut accum = T::one();
+
+        if self.is_in_row_echelon_form() {
+            return accum;
+        }
+
+        // We operate column by column or row by row depending on which is the bigger one
+        // but frankly, it doesn't matter. Most matrices have more columns than rows though
+        // so the variable is named column
+
+        for current_column in 0..M.min(N) {
+            // If this column consists only of zeroes, it "accounts for a redundant
+            // variable" if you're speaking in terms of systems of linear equations.
+            // Hence, it can safely be ignored.
+
+            if self.col(current_column).iter().all(|e| e.is_zero()) {
+                continue;
+            }
+
+            // To get zeroes in all rows below this one, we first calculate an
+            // intermediate row. This row is used to subtract from per row,
+            // such that the current row gets a zero.
+            //
+            // If the pivot is zero at this point, there will be a zero division
+            // in the following algorithm. However, because of the `continue` statement
+            // above, it is guaranteed that there is at least one nonzero element in
+            // this column. So, we swap the row containing that element and the current one
+            // around.
+            // Note that this shouldn't check entries in the column that come before the
+            // currently checked row! Those might be nonzero, but are irrelevant here.
+
+            if self[(current_column, current_column)].is_zero() {
+                let index_of_nonzero = self
+                    .col(current_column)
+                    .iter()
+                    .enumerate()
+                    .skip(current_column) // to ignore previous possibly nonzero values
+                    .find(|(_idx, elem)| !elem.is_zero())
+                    .unwrap()
+                    .0;
+
+                self.row_swap(current_column, index_of_nonzero);
+                accum = accum.neg(); // swapping two rows multiplies the determinant by negative one
+            }
+
+            let pivot = self[(current_column, current_column)];
+
+            //println!("before:\n{self}");
+
+            //println!("on column {current_column}. pivot is {pivot}");
+
+            for row_below in (current_column + 1)..N {
+                let factor = self[(row_below, current_column)] / pivot;
+
+                //println!("making a zero in row {row_below}. factor is {factor}");
+
+                if factor.is_zero() {
+                    continue;
+                }
+
+                let intermediate_row: Vec<T> = self
+                    .row(current_column)
+                    .iter()
+                    .map(|e| *e * factor)
+                    .collect();
+
+                //println!("intermediate row: {intermediate_row:?}");
+
+                for i in 0..M {
+                    self[(row_below, i)] = self[(row_below, i)] - intermediate_row[i];
+                }
+            }
+
+            //println!("{self}");
+
+            accum = accum * self[(current_column, current_column)];
+        }
+
+        accum
+    }
+
+    /// Checks if the matrix is in row echelon form.
+    pub fn is_in_row_echelon_form(&self) -> bool {
+        if self == &Matrix::zeroes(N, M) {
+            return true;
+        }
+        let mut rows = (0..N).map(|i| self.row(i)).peekable();
+        let mut leading_zeroes = -1isize;
+        for _i in 0..N {
+            let r = rows.next().unwrap();
+            // println!(row is {r:#?}");
+            if r.iter().all(|e| e.is_zero()) {
+                // println!("all zeroes");
+                match rows.peek() {
+                    None => {
+                        // println!("and we're the last");
+                        return true;
+                    } // we are the last row
+                    Some(next_row) => {
+                        // println!("we're not the last");
+                        if next_row.iter().all(|e| e.is_zero()) {
+                            // println!("the next row is all zeroes so we continue");
+                            continue;
+                        } else {
+                            // println!("the next row is not all zeroes so no row ech form");
+                            return false;
+                        }
+                    }
+                }
+            }
+            // the row should contain more than leading_zeroes zeroes
+            // before anything else appears in the row (it's safe to unwrap here
+            // since we checked for all-zero rows above)
+            let first_nonzero_entry = r.iter().position(|e| !e.is_zero()).unwrap() as isize;
+
+            // println!("our first nonzero entry is {first_nonzero_entry}");
+
+            if first_nonzero_entry > leading_zeroes {
+                // println!("which is greater than our last number of leading zeroes :)");
+                leading_zeroes = first_nonzero_entry;
+            } else {
+                // println!("which is not greater than our last number of leading zeroes");
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    [(); N * N]:,
+    T: Zero + One + Copy + NumOps + Signed + PartialEq + PartialOrd,
+    [(); (N - 1) * (N - 1)]:,
+{
+    /// Calculates the matrix of cofactors.
+    pub fn comatrix(&self) -> Self {
+        let mut result: Matrix<T, N, N> = Matrix::zeroes(N, N);
+
+        for x in 0..N {
+            for y in 0..N {
+                result[(x, y)] = self.square_submatrix(x, y).determinant() * {
+                    if (x + y) % 2usize == 0 {
+                        T::one()
+                    } else {
+                        T::one().neg()
+                    }
+                };
+            }
+        }
+
+        result
+    }
+
+    /// Calculates the adjugate (also known as the classical adjoint) of a square matrix.
+    pub fn adjugate(&self) -> Self {
+        self.comatrix().transpose()
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    [(); N * N]:,
+    T: Copy + NumOps + Zero + One + PartialEq + PartialOrd + Signed,
+{
+    /// Calculates the determinant of a Matrix.
+    /// Requires the relevant type to implement NumOps (Add, Sub, Mul, Div), as well
+    /// as Copy, Zero, One and Neg.
+    ///
+    /// Matrices up to 3×3 use the explicit formulas. Larger ones are reduced by
+    /// elimination with partial pivoting in O(N³) operations; see `determinant_bareiss`.
+    pub fn determinant(&self) -> T {
+        match N {
+            // The empty product: by convention, the empty matrix has determinant one.
+            0 => T::one(),
+            1 => self[(0, 0)],
+            2 => self[(0, 0)] * self[(1, 1)] - self[(0, 1)] * self[(1, 0)],
+            3 => {
+                self[(0, 0)] * self[(1, 1)] * self[(2, 2)]
+                    + self[(0, 1)] * self[(1, 2)] * self[(2, 0)]
+                    + self[(0, 2)] * self[(1, 0)] * self[(2, 1)]
+                    - self[(0, 2)] * self[(1, 1)] * self[(2, 0)]
+                    - self[(0, 1)] * self[(1, 0)] * self[(2, 2)]
+                    - self[(0, 0)] * self[(1, 2)] * self[(2, 1)]
+            }
+            _ => self.determinant_bareiss(),
+        }
+    }
+
+    /// Checks if the matrix is invertible, i.e. if its determinant is nonzero. For
+    /// floating-point matrices, `is_invertible_within` is usually the better choice.
+    pub fn is_invertible(&self) -> bool {
+        !self.determinant().is_zero()
+    }
+
+    /// Calculates the determinant with the Bareiss algorithm, a fraction-free variant of
+    /// Gaussian elimination. Every division it makes is exact, so for integer matrices
+    /// all intermediate values stay integers: in fact, each of them is the determinant of
+    /// a submatrix, which keeps them from growing much larger than the result. It takes
+    /// O(N³) operations.
+    ///
+    /// The type only needs to support exact division of values that are multiples of
+    /// each other, which holds for integers as well as rationals and floats.
+    ///
+    /// Rows are swapped so that every pivot is the entry with the largest absolute value
+    /// in its column (partial pivoting). That keeps rounding errors small for floats,
+    /// and doesn't affect exactness for integers.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<i64, 3, 3> =
+    ///     Matrix::from(vec![vec![2, -1, 0], vec![-1, 2, -1], vec![0, -1, 2]]);
+    /// assert_eq!(m.determinant_bareiss(), 4);
+    /// ```
+    pub fn determinant_bareiss(&self) -> T {
+        let mut a = *self;
+        let mut sign = T::one();
+        let mut previous_pivot = T::one();
+
+        for k in 0..N.saturating_sub(1) {
+            let pivot_row = (k + 1..N).fold(k, |best, i| {
+                if a[(i, k)].abs() > a[(best, k)].abs() {
+                    i
+                } else {
+                    best
+                }
+            });
+            if a[(pivot_row, k)].is_zero() {
+                return T::zero();
+            }
+            if pivot_row != k {
+                for y in 0..N {
+                    a.swap((k, y), (pivot_row, y));
+                }
+                sign = -sign;
+            }
+
+            for i in k + 1..N {
+                for j in k + 1..N {
+                    a[(i, j)] = (a[(i, j)] * a[(k, k)] - a[(i, k)] * a[(k, j)]) / previous_pivot;
+                }
+            }
+            previous_pivot = a[(k, k)];
+        }
+
+        match N {
+            0 => T::one(),
+            _ => sign * a[(N - 1, N - 1)],
+        }
+    }
+
+    /// Tries to decompose the matrix into a lower triangular and upper triangular matrix
+    /// according to Crout's method. Returns an optional tuple `Some((lower, upper))`.
+    /// The implementation is taken straight from Wikipedia:
+    /// https://en.wikipedia.org/w/index.php?title=Crout_matrix_decomposition&oldid=956132782
+    pub fn crout_decomposition(self) -> Option<(Self, Self)> {
+        let mut lower: Matrix<T, N, N> = Matrix::zeroes(N, N);
+        let mut upper: Matrix<T, N, N> = Matrix::zeroes(N, N);
+
+        for i in 0..N {
+            upper[(i, i)] = T::one();
+        }
+
+        for j in 0..N {
+            for i in 0..N {
+                let mut sum = T::zero();
+                for k in 0..j {
+                    sum = sum + lower[(i, k)] * upper[(k, j)];
+                }
+                lower[(i, j)] = self[(i, j)] - sum;
+            }
+
+            for i in j..N {
+                let mut sum = T::zero();
+                for k in 0..j {
+                    sum = sum + lower[(j, k)] * upper[(k, i)];
+                }
+                if lower[(j, j)].is_zero() {
+                    // The determinant is zero, and hence this decomposition cannot be returned.
+                    return None;
+                }
+                upper[(j, i)] = (self[(j, i)] - sum) / lower[(j, j)];
+            }
+        }
+
+        Some((lower, upper))
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    [(); N * N]:,
+    T: Float,
+{
+    /// Attempts to calculate the inverse of the Matrix. Note that this is only
+    /// implemented for `Matrix<T, N, N>`, i.e. square matrices.
+    ///
+    /// The inverse is calculated with Gauss–Jordan elimination with partial pivoting:
+    /// the matrix is reduced to the identity while the same row operations are applied
+    /// to an identity matrix, which turns it into the inverse. This takes O(N³)
+    /// operations. `try_inverse` additionally reports matrices that are too
+    /// ill-conditioned to invert reliably. For integer matrices, use `inverse_exact`.
+    ///
+    /// ## Returns
+    ///
+    /// An `Option<Self>`: `None` if the matrix isn't invertible and `Some(m)` with
+    /// m being the inverted matrix.
+    pub fn inverse(&self) -> Option<Self> {
+        let mut reduced = *self;
+        let mut inverse: Matrix<T, N, N> =
+            Matrix::from_fn(|x, y| if x == y { T::one() } else { T::zero() });
+
+        for c in 0..N {
+            let pivot_row = (c + 1..N).fold(c, |best, r| {
+                if reduced[(r, c)].abs() > reduced[(best, c)].abs() {
+                    r
+                } else {
+                    best
+                }
+            });
+            let pivot = reduced[(pivot_row, c)];
+            if pivot.is_zero() {
+                return None;
+            }
+
+            for i in 0..N {
+                reduced.swap((c, i), (pivot_row, i));
+                inverse.swap((c, i), (pivot_row, i));
+            }
+            for i in 0..N {
+                reduced[(c, i)] = reduced[(c, i)] / pivot;
+                inverse[(c, i)] = inverse[(c, i)] / pivot;
+            }
+
+            for r in (0..N).filter(|&r| r != c) {
+                let factor = reduced[(r, c)];
+                if factor.is_zero() {
+                    continue;
+                }
+                for i in 0..N {
+                    reduced[(r, i)] = reduced[(r, i)] - factor * reduced[(c, i)];
+                    inverse[(r, i)] = inverse[(r, i)] - factor * inverse[(c, i)];
+                }
+            }
+        }
+
+        Some(inverse)
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    [(); N * N]:,
+    T: PrimInt + Signed,
+{
+    /// Attempts to calculate the inverse of an integer matrix. The inverse only has
+    /// integer entries if the determinant is ±1, so any other matrix gives `None`, even
+    /// if it is invertible over the rationals.
+    ///
+    /// The matrix is reduced by Gauss–Jordan elimination in the style of
+    /// `determinant_bareiss`: every update is divided by the previous pivot, which is
+    /// exact, so the entries stay integral. Once the matrix has been reduced to `dI`, with
+    /// `d` the determinant of the row-swapped matrix, the identity it started from has
+    /// turned into `dA⁻¹`, which only has to be divided by `d`. This takes O(N³)
+    /// operations, but the intermediate entries grow like the determinant, so large
+    /// matrices may overflow.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![2, 1], vec![1, 1]]);
+    /// assert_eq!(m.inverse_exact(), Some(Matrix::from(vec![vec![1, -1], vec![-1, 2]])));
+    ///
+    /// // the inverse of a matrix with determinant 2 has fractional entries
+    /// let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![2, 0], vec![0, 1]]);
+    /// assert_eq!(m.inverse_exact(), None);
+    /// ```
+    pub fn inverse_exact(&self) -> Option<Self> {
+        let mut reduced = *self;
+        let mut inverse: Matrix<T, N, N> =
+            Matrix::from_fn(|x, y| if x == y { T::one() } else { T::zero() });
+        let mut previous_pivot = T::one();
+
+        for c in 0..N {
+            let pivot_row = (c..N).find(|&r| !reduced[(r, c)].is_zero())?;
+            for i in 0..N {
+                reduced.swap((c, i), (pivot_row, i));
+                inverse.swap((c, i), (pivot_row, i));
+            }
+            let pivot = reduced[(c, c)];
+
+            for r in (0..N).filter(|&r| r != c) {
+                let factor = reduced[(r, c)];
+                for i in 0..N {
+                    if i != c {
+                        reduced[(r, i)] =
+                            (pivot * reduced[(r, i)] - factor * reduced[(c, i)]) / previous_pivot;
+                    }
+                    inverse[(r, i)] =
+                        (pivot * inverse[(r, i)] - factor * inverse[(c, i)]) / previous_pivot;
+                }
+                reduced[(r, c)] = T::zero();
+            }
+            previous_pivot = pivot;
+        }
+
+        if inverse
+            .data
+            .iter()
+            .any(|&e| e % previous_pivot != T::zero())
+        {
+            return None;
+        }
+        Some(Matrix::from_array(inverse.data.map(|e| e / previous_pivot)))
+    }
+}
+
+#[allow(clippy::identity_op)]
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    [(); N * N]:,
+    [(); N * 1]:,
+    [(); 1 * N]:,
+    T: Copy + NumOps + Zero + One,
+{
+    /// Given the inverse `inv` of this matrix `A`, calculates the inverse of the rank-1
+    /// update `A + uv` with the Sherman–Morrison formula
+    ///
+    /// `(A + uv)⁻¹ = A⁻¹ - (A⁻¹u)(vA⁻¹) / (1 + vA⁻¹u)`.
+    ///
+    /// This takes O(N²) operations, rather than the O(N³) of inverting from scratch.
+    /// Only `inv` is read, so the caller has to make sure it really is the inverse of `A`.
+    ///
+    /// Returns `None` if the denominator `1 + vA⁻¹u` is zero, in which case `A + uv`
+    /// is singular.
+    pub fn inverse_update_rank1(
+        &self,
+        inv: &Self,
+        u: &Matrix<T, N, 1>,
+        v: &Matrix<T, 1, N>,
+    ) -> Option<Self> {
+        let inv_u = inv.mul_vec(u);
+        let v_inv: Matrix<T, 1, N> =
+            Matrix::from_fn(|_, y| (0..N).fold(T::zero(), |acc, i| acc + v.data[i] * inv[(i, y)]));
+        let denominator = (0..N).fold(T::one(), |acc, i| acc + v.data[i] * inv_u.data[i]);
+        if denominator.is_zero() {
+            return None;
+        }
+
+        let mut result = *inv;
+        for x in 0..N {
+            for y in 0..N {
+                result[(x, y)] = result[(x, y)] - inv_u.data[x] * v_inv.data[y] / denominator;
+            }
+        }
+        Some(result)
+    }
+}
+
+/// Panics if `perm` isn't a permutation of `0..perm.len()`, i.e. if it doesn't contain
+/// every index exactly once.
+fn assert_permutation(perm: &[usize]) {
+    let mut seen = vec![false; perm.len()];
+    for &i in perm {
+        assert!(i < perm.len() && !seen[i], "{perm:?} is not a permutation.");
+        seen[i] = true;
+    }
+}
+
+/// Returns the parity of a permutation: `1` if it can be written as an even number of
+/// transpositions and `-1` otherwise. The permutation is given as the list of images,
+/// so `perm[i]` is where `i` is sent.
+///
+/// ## Panics
+///
+/// If `perm` isn't a permutation of `0..perm.len()`.
+pub fn permutation_parity(perm: &[usize]) -> i32 {
+    let mut visited = vec![false; perm.len()];
+    let mut transpositions = 0;
+    for start in 0..perm.len() {
+        if visited[start] {
+            continue;
+        }
+        // A cycle of length k is made up of k - 1 transpositions.
+        let mut current = start;
+        let mut length = 0;
+        while !visited[current] {
+            visited[current] = true;
+            current = perm[current];
+            assert!(current < perm.len(), "{perm:?} is not a permutation.");
+            length += 1;
+        }
+        assert_eq!(current, start, "{perm:?} is not a permutation.");
+        transpositions += length - 1;
+    }
+
+    if transpositions % 2 == 0 {
+        1
+    } else {
+        -1
+    }
+}
+
+/// Calculates a determinant from an LU decomposition `PA = LU`, where `L` has a unit
+/// diagonal: the product of the diagonal of `upper`, with its sign flipped if the row
+/// permutation `perm` is odd.
+pub fn det_from_lu<T, const N: usize>(upper: &Matrix<T, N, N>, perm: &[usize]) -> T
+where
+    [(); N * N]:,
+    T: Copy + One + Mul<Output = T> + Neg<Output = T>,
+{
+    let product = (0..N).fold(T::one(), |acc, i| acc * upper[(i, i)]);
+    if permutation_parity(perm) == 1 {
+        product
+    } else {
+        -product
+    }
+}
+
+/// The reasons why inverting a matrix can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InverseError {
+    /// The matrix is singular: during elimination, a column without any nonzero
+    /// pivot was found.
+    Singular,
+    /// The matrix may technically be invertible, but a pivot was found that is so small
+    /// compared to the entries of the matrix that the result would be dominated by
+    /// rounding errors.
+    IllConditioned,
+    /// The matrix has an infinite or NaN entry, or the elimination overflowed.
+    NonFinite,
+}
+
+impl Display for InverseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InverseError::Singular => write!(f, "the matrix is singular"),
+            InverseError::IllConditioned => {
+                write!(
+                    f,
+                    "the matrix is too ill-conditioned to be inverted reliably"
+                )
+            }
+            InverseError::NonFinite => write!(f, "the matrix has infinite or NaN entries"),
+        }
+    }
+}
+
+impl std::error::Error for InverseError {}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    [(); N * N]:,
+    T: Float,
+{
+    /// Attempts to calculate the inverse of the matrix using Gauss-Jordan elimination
+    /// with partial pivoting. Unlike `inverse`, this reports why the inversion failed.
+    ///
+    /// A pivot counts as too small if its absolute value is at most `N * ε * max|aᵢⱼ|`,
+    /// with ε being the machine epsilon of `T`.
+    pub fn try_inverse(&self) -> Result<Self, InverseError> {
+        if self.data.iter().any(|e| !e.is_finite()) {
+            return Err(InverseError::NonFinite);
+        }
+        let tolerance = T::from(N).unwrap()
+            * T::epsilon()
+            * self.data.iter().fold(T::zero(), |acc, e| acc.max(e.abs()));
+
+        let mut reduced = *self;
+        let mut inverse: Matrix<T, N, N> = Matrix::identity(N);
+
+        for c in 0..N {
+            // A NaN in the column always gets picked, so it can't slip past.
+            let pivot_row = (c..N)
+                .max_by(|&a, &b| nan_last(&reduced[(a, c)].abs(), &reduced[(b, c)].abs()))
+                .unwrap();
+            let pivot = reduced[(pivot_row, c)];
+
+            if !pivot.is_finite() {
+                return Err(InverseError::NonFinite);
+            }
+            if pivot.is_zero() {
+                return Err(InverseError::Singular);
+            }
+            if pivot.abs() <= tolerance {
+                return Err(InverseError::IllConditioned);
+            }
+
+            for i in 0..N {
+                reduced.data.swap(c * N + i, pivot_row * N + i);
+                inverse.data.swap(c * N + i, pivot_row * N + i);
+            }
+
+            for i in 0..N {
+                reduced[(c, i)] = reduced[(c, i)] / pivot;
+                inverse[(c, i)] = inverse[(c, i)] / pivot;
+            }
+
+            for r in 0..N {
+                if r == c {
+                    continue;
+                }
+                let factor = reduced[(r, c)];
+                if factor.is_zero() {
+                    continue;
+                }
+                for i in 0..N {
+                    reduced[(r, i)] = reduced[(r, i)] - factor * reduced[(c, i)];
+                    inverse[(r, i)] = inverse[(r, i)] - factor * inverse[(c, i)];
+                }
+            }
+        }
+
+        Ok(inverse)
+    }
+
+    /// Checks if the matrix is invertible, treating pivots with an absolute value of at
+    /// most `tol` as zero. This runs Gaussian elimination with partial pivoting without
+    /// building the inverse, so it is a cheap guard before calling `inverse`. Unlike
+    /// `is_invertible`, this doesn't get fooled by matrices which are singular up to
+    /// rounding errors.
+    ///
+    /// Matrices with infinite or NaN entries are never considered invertible.
+    pub fn is_invertible_within(&self, tol: T) -> bool {
+        let mut reduced = *self;
+        for c in 0..N {
+            let pivot_row = (c..N)
+                .max_by(|&a, &b| nan_last(&reduced[(a, c)].abs(), &reduced[(b, c)].abs()))
+                .unwrap();
+            let pivot = reduced[(pivot_row, c)];
+            if !pivot.is_finite() || pivot.abs() <= tol {
+                return false;
+            }
+
+            for i in c..N {
+                reduced.data.swap(c * N + i, pivot_row * N + i);
+            }
+            for r in c + 1..N {
+                let factor = reduced[(r, c)] / pivot;
+                for i in c..N {
+                    reduced[(r, i)] = reduced[(r, i)] - factor * reduced[(c, i)];
+                }
+            }
+        }
+        true
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    [(); N * N]:,
+    T: Copy + Zero + PartialEq,
+{
+    /// Checks if a square matrix is symmetric.
+    pub fn is_symmetric(&self) -> bool {
+        self.transpose() == *self
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    [(); N * N]:,
+    T: Copy + Zero + PartialEq + Neg<Output = T>,
+{
+    /// Checks if a square matrix is skew-symmetric.
+    pub fn is_skew_symmetric(&self) -> bool {
+        self.transpose() == -*self
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    [(); N * N]:,
+    T: Float,
+{
+    /// Calculates the symmetric part `(A + Aᵀ) / 2` of a square matrix. Together with
+    /// `skew_part` this splits any square matrix into a symmetric and a skew-symmetric
+    /// matrix that sum to it.
+    pub fn symmetric_part(&self) -> Self {
+        let two = T::one() + T::one();
+        Matrix::from_fn(|x, y| (self[(x, y)] + self[(y, x)]) / two)
+    }
+
+    /// Calculates the skew-symmetric part `(A - Aᵀ) / 2` of a square matrix.
+    pub fn skew_part(&self) -> Self {
+        let two = T::one() + T::one();
+        Matrix::from_fn(|x, y| (self[(x, y)] - self[(y, x)]) / two)
+    }
+}
+
+// Overflow-aware integer arithmetic.
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    [(); N * M]:,
+    T: CheckedAdd + CheckedMul + Zero + Copy,
+{
+    /// Adds two matrices, returning `None` if any of the additions overflows.
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let mut result = *self;
         for i in 0..N * M {
-            self.data[i] += rhs.data[i];
+            result.data[i] = self.data[i].checked_add(&rhs.data[i])?;
+        }
+        Some(result)
+    }
+
+    /// Multiplies two matrices, returning `None` if any of the multiplications or additions
+    /// involved overflows.
+    pub fn checked_mul<const P: usize>(&self, rhs: &Matrix<T, M, P>) -> Option<Matrix<T, N, P>>
+    where
+        [(); M * P]:,
+        [(); N * P]:,
+    {
+        let mut result: Matrix<T, N, P> = Matrix::zeroes(N, P);
+        for x in 0..N {
+            for y in 0..P {
+                let mut dot_product = T::zero();
+                for i in 0..M {
+                    dot_product =
+                        dot_product.checked_add(&self[(x, i)].checked_mul(&rhs[(i, y)])?)?;
+                }
+                result[(x, y)] = dot_product;
+            }
         }
+        Some(result)
     }
 }
 
-// Subtraction.
-impl<T, Q, const N: usize, const M: usize> Sub<Matrix<Q, N, M>> for Matrix<T, N, M>
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
 where
-    T: Sub<Q, Output = T> + Copy,
-    Q: Copy,
     [(); N * M]:,
+    T: SaturatingAdd + SaturatingMul + Zero + Copy,
 {
-    type Output = Matrix<T, N, M>;
-    fn sub(self, rhs: Matrix<Q, N, M>) -> Self::Output {
-        assert_eq!(
-            self.rows, rhs.rows,
-            "Matrices do not have the same dimension."
-        );
-        let mut data: [T; N * M] = self.data;
+    /// Adds two matrices, clamping every element to the bounds of `T` instead of
+    /// overflowing.
+    pub fn saturating_add(&self, rhs: &Self) -> Self {
+        let mut result = *self;
+        for i in 0..N * M {
+            result.data[i] = self.data[i].saturating_add(&rhs.data[i]);
+        }
+        result
+    }
+
+    /// Multiplies two matrices, clamping every intermediate product and sum to the bounds
+    /// of `T` instead of overflowing.
+    pub fn saturating_mul<const P: usize>(&self, rhs: &Matrix<T, M, P>) -> Matrix<T, N, P>
+    where
+        [(); M * P]:,
+        [(); N * P]:,
+    {
+        let mut result: Matrix<T, N, P> = Matrix::zeroes(N, P);
+        for x in 0..N {
+            for y in 0..P {
+                for i in 0..M {
+                    result[(x, y)] =
+                        result[(x, y)].saturating_add(&self[(x, i)].saturating_mul(&rhs[(i, y)]));
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    [(); N * M]:,
+    T: WrappingAdd + WrappingMul + Zero + Copy,
+{
+    /// Adds two matrices, wrapping around at the bounds of `T` instead of overflowing.
+    pub fn wrapping_add(&self, rhs: &Self) -> Self {
+        let mut result = *self;
         for i in 0..N * M {
-            data[i] = data[i] - rhs.data[i];
+            result.data[i] = self.data[i].wrapping_add(&rhs.data[i]);
+        }
+        result
+    }
+
+    /// Multiplies two matrices, wrapping around at the bounds of `T` instead of overflowing.
+    pub fn wrapping_mul<const P: usize>(&self, rhs: &Matrix<T, M, P>) -> Matrix<T, N, P>
+    where
+        [(); M * P]:,
+        [(); N * P]:,
+    {
+        let mut result: Matrix<T, N, P> = Matrix::zeroes(N, P);
+        for x in 0..N {
+            for y in 0..P {
+                for i in 0..M {
+                    result[(x, y)] =
+                        result[(x, y)].wrapping_add(&self[(x, i)].wrapping_mul(&rhs[(i, y)]));
+                }
+            }
+        }
+        result
+    }
+}
+
+// Solving linear systems.
+
+/// Brings a row-major buffer of `rows` by `cols` elements into reduced row echelon form
+/// in-place, using Gauss-Jordan elimination with partial pivoting. Returns the indices of
+/// the pivot columns.
+///
+/// This works on a plain slice rather than on a `Matrix` so that augmented matrices,
+/// whose dimensions would be a const expression of a const expression, can be reduced too.
+/// Entries whose absolute value is at most `max(rows, cols) * ε * max|aᵢⱼ|` are treated as
+/// zero, so that rounding errors don't produce spurious pivots.
+fn reduce_to_rref<T: Float>(data: &mut [T], rows: usize, cols: usize) -> Vec<usize> {
+    let tolerance = T::from(rows.max(cols)).unwrap()
+        * T::epsilon()
+        * data.iter().fold(T::zero(), |acc, e| acc.max(e.abs()));
+    let mut pivot_columns = Vec::new();
+    let mut current_row = 0;
+
+    for c in 0..cols {
+        if current_row == rows {
+            break;
+        }
+
+        let pivot_row = (current_row..rows)
+            .max_by(|&a, &b| nan_last(&data[a * cols + c].abs(), &data[b * cols + c].abs()))
+            .unwrap();
+        if data[pivot_row * cols + c].abs() <= tolerance {
+            for r in current_row..rows {
+                data[r * cols + c] = T::zero();
+            }
+            continue;
+        }
+
+        for i in 0..cols {
+            data.swap(current_row * cols + i, pivot_row * cols + i);
+        }
+
+        let pivot = data[current_row * cols + c];
+        for i in 0..cols {
+            data[current_row * cols + i] = data[current_row * cols + i] / pivot;
+        }
+
+        for r in 0..rows {
+            if r == current_row {
+                continue;
+            }
+            let factor = data[r * cols + c];
+            for i in 0..cols {
+                data[r * cols + i] = data[r * cols + i] - factor * data[current_row * cols + i];
+            }
+        }
+
+        pivot_columns.push(c);
+        current_row += 1;
+    }
+
+    pivot_columns
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    [(); N * M]:,
+    T: Float,
+{
+    /// Returns the reduced row echelon form of the matrix.
+    pub fn rref(&self) -> Self {
+        let mut result = *self;
+        reduce_to_rref(&mut result.data, N, M);
+        result
+    }
+
+    /// Solves the system `Ax = b` for any matrix `A`, square or not. The augmented
+    /// matrix `[A | b]` is brought into reduced row echelon form, after which the
+    /// system is classified as having a unique solution, no solution, or infinitely
+    /// many. See `Solution` for details.
+    #[allow(clippy::identity_op)]
+    pub fn solve_general(&self, b: &Matrix<T, N, 1>) -> Solution<T, M>
+    where
+        [(); N * 1]:,
+        [(); M * 1]:,
+    {
+        let width = M + 1;
+        let mut augmented = Vec::with_capacity(N * width);
+        for r in 0..N {
+            augmented.extend_from_slice(&self.row(r));
+            augmented.push(b.data[r]);
+        }
+        let pivot_columns = reduce_to_rref(&mut augmented, N, width);
+
+        if pivot_columns.last() == Some(&M) {
+            return Solution::Inconsistent;
+        }
+
+        // The particular solution sets all free variables to zero.
+        let mut particular: Matrix<T, M, 1> = Matrix::zeroes(M, 1);
+        for (r, &c) in pivot_columns.iter().enumerate() {
+            particular.data[c] = augmented[r * width + M];
+        }
+
+        if pivot_columns.len() == M {
+            return Solution::Unique(particular);
+        }
+
+        // Every free variable gives rise to one basis vector of the null space.
+        let null_space = (0..M)
+            .filter(|c| !pivot_columns.contains(c))
+            .map(|free| {
+                let mut v: Matrix<T, M, 1> = Matrix::zeroes(M, 1);
+                v.data[free] = T::one();
+                for (r, &c) in pivot_columns.iter().enumerate() {
+                    v.data[c] = -augmented[r * width + free];
+                }
+                v
+            })
+            .collect();
+
+        Solution::Infinite {
+            particular,
+            null_space,
+        }
+    }
+}
+
+/// The solution set of a linear system `Ax = b`, as returned by `solve_general`.
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::identity_op)]
+pub enum Solution<T, const M: usize>
+where
+    [(); M * 1]:,
+{
+    /// The system has exactly one solution.
+    Unique(Matrix<T, M, 1>),
+    /// The system has infinitely many solutions: every solution is the particular
+    /// solution plus some linear combination of the null space basis vectors.
+    Infinite {
+        /// A solution of the system.
+        particular: Matrix<T, M, 1>,
+        /// A basis of the null space of `A`.
+        null_space: Vec<Matrix<T, M, 1>>,
+    },
+    /// The system has no solutions.
+    Inconsistent,
+}
+
+#[allow(clippy::identity_op)]
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    [(); N * N]:,
+    [(); N * 1]:,
+    T: Float,
+{
+    /// Solves the system `Ax = b` for a square matrix `A`. Returns `None` unless the
+    /// system has exactly one solution; use `solve_general` to tell the other cases apart.
+    pub fn solve(&self, b: &Matrix<T, N, 1>) -> Option<Matrix<T, N, 1>> {
+        match self.solve_general(b) {
+            Solution::Unique(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Solves the system `Ax = b` for a tridiagonal matrix `A` using the Thomas algorithm,
+    /// which takes O(N) operations rather than the O(N³) of general elimination.
+    ///
+    /// The algorithm doesn't pivot, so it is only guaranteed to be stable for matrices
+    /// that are diagonally dominant or symmetric positive definite. Returns `None` if it
+    /// runs into a zero pivot.
+    ///
+    /// Only the three central diagonals are read; every other entry is assumed to be zero.
+    ///
+    /// ## Panics
+    ///
+    /// In debug builds, if the matrix isn't tridiagonal. Checking that means reading all
+    /// N² entries, so release builds skip it.
+    pub fn solve_tridiagonal(&self, b: &Matrix<T, N, 1>) -> Option<Matrix<T, N, 1>> {
+        debug_assert!(
+            self.iter_indexed()
+                .all(|((x, y), e)| x.abs_diff(y) <= 1 || e.is_zero()),
+            "The matrix is not tridiagonal."
+        );
+
+        // The modified super-diagonal and right-hand side of the forward sweep.
+        let mut sup = [T::zero(); N];
+        let mut rhs = [T::zero(); N];
+        for i in 0..N {
+            // The entry below the diagonal, and the results of the previous step.
+            let (below, previous_sup, previous_rhs) = if i == 0 {
+                (T::zero(), T::zero(), T::zero())
+            } else {
+                (self[(i, i - 1)], sup[i - 1], rhs[i - 1])
+            };
+            let pivot = self[(i, i)] - below * previous_sup;
+            if pivot.is_zero() {
+                return None;
+            }
+            if i + 1 < N {
+                sup[i] = self[(i, i + 1)] / pivot;
+            }
+            rhs[i] = (b.data[i] - below * previous_rhs) / pivot;
+        }
+
+        let mut x: Matrix<T, N, 1> = Matrix::zeroes(N, 1);
+        for i in (0..N).rev() {
+            x.data[i] = if i + 1 < N {
+                rhs[i] - sup[i] * x.data[i + 1]
+            } else {
+                rhs[i]
+            };
+        }
+        Some(x)
+    }
+
+    /// Approximately solves the system `Ax = b` with the Jacobi method: every iteration
+    /// solves each equation for its own variable, using the previous iterate for all
+    /// other variables. Starting from zero, this stops as soon as the residual
+    /// `‖Ax - b‖∞` is at most `tol`, and returns `None` if that hasn't happened after
+    /// `iters` iterations, or if there is a zero on the diagonal.
+    ///
+    /// The method is guaranteed to converge for strictly diagonally dominant matrices
+    /// (see `is_diagonally_dominant`); for other matrices it may diverge.
+    pub fn solve_jacobi(
+        &self,
+        b: &Matrix<T, N, 1>,
+        iters: usize,
+        tol: T,
+    ) -> Option<Matrix<T, N, 1>> {
+        if (0..N).any(|i| self[(i, i)].is_zero()) {
+            return None;
+        }
+
+        let mut x: Matrix<T, N, 1> = Matrix::zeroes(N, 1);
+        for _ in 0..iters {
+            if self.residual(&x, b).norm_inf() <= tol {
+                return Some(x);
+            }
+            x = Matrix::from_array(std::array::from_fn(|i| {
+                let sum = (0..N)
+                    .filter(|&j| j != i)
+                    .fold(T::zero(), |acc, j| acc + self[(i, j)] * x.data[j]);
+                (b.data[i] - sum) / self[(i, i)]
+            }));
+        }
+
+        if self.residual(&x, b).norm_inf() <= tol {
+            Some(x)
+        } else {
+            None
+        }
+    }
+
+    /// Approximately solves the system `Ax = b` with the Gauss-Seidel method. This is
+    /// like the Jacobi method, except that every updated variable is used straight away,
+    /// which typically makes it converge about twice as fast. The stopping criteria and
+    /// convergence requirements are the same as for `solve_jacobi`.
+    pub fn solve_gauss_seidel(
+        &self,
+        b: &Matrix<T, N, 1>,
+        iters: usize,
+        tol: T,
+    ) -> Option<Matrix<T, N, 1>> {
+        if (0..N).any(|i| self[(i, i)].is_zero()) {
+            return None;
+        }
+
+        let mut x: Matrix<T, N, 1> = Matrix::zeroes(N, 1);
+        for _ in 0..iters {
+            if self.residual(&x, b).norm_inf() <= tol {
+                return Some(x);
+            }
+            for i in 0..N {
+                let sum = (0..N)
+                    .filter(|&j| j != i)
+                    .fold(T::zero(), |acc, j| acc + self[(i, j)] * x.data[j]);
+                x.data[i] = (b.data[i] - sum) / self[(i, i)];
+            }
+        }
+
+        if self.residual(&x, b).norm_inf() <= tol {
+            Some(x)
+        } else {
+            None
+        }
+    }
+}
+
+/// How elimination routines such as `lu_with` pick their pivots. Every step of
+/// elimination divides by the pivot, so picking a large one keeps rounding errors from
+/// being amplified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PivotStrategy {
+    /// Always use the diagonal entry. This is the cheapest option, but it fails on a zero
+    /// pivot even if the matrix is invertible, and small pivots can make the result very
+    /// inaccurate. Only use it for matrices known not to need pivoting, such as
+    /// diagonally dominant or symmetric positive definite ones.
+    None,
+    /// Use the entry with the largest absolute value in the current column, swapping rows.
+    /// This costs O(N²) comparisons in total and is stable for nearly all matrices that
+    /// come up in practice, which is why it is the default.
+    Partial,
+    /// Use the entry with the largest absolute value in the whole remaining submatrix,
+    /// swapping both rows and columns. This costs O(N³) comparisons, on the same order
+    /// as the elimination itself, but it is stable even for matrices whose entries
+    /// differ wildly in magnitude.
+    Complete,
+}
+
+/// An LU decomposition `PAQ = LU` with row and column permutations, as returned by
+/// `lu_with`. Without column pivoting, `Q` is the identity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LuDecomposition<T, const N: usize>
+where
+    [(); N * N]:,
+{
+    /// The lower triangular factor `L`, which has ones on its diagonal.
+    pub lower: Matrix<T, N, N>,
+    /// The upper triangular factor `U`.
+    pub upper: Matrix<T, N, N>,
+    /// The row permutation `P`: row `i` of `PA` is row `row_perm[i]` of `A`.
+    pub row_perm: [usize; N],
+    /// The column permutation `Q`: column `j` of `AQ` is column `col_perm[j]` of `A`.
+    pub col_perm: [usize; N],
+}
+
+#[allow(clippy::identity_op)]
+impl<T, const N: usize> LuDecomposition<T, N>
+where
+    [(); N * N]:,
+    [(); N * 1]:,
+    T: Float,
+{
+    /// Solves `Ax = b` by forward substitution with `L` and back substitution with `U`,
+    /// which takes O(N²) operations. The decomposition can be reused for any number of
+    /// right-hand sides.
+    pub fn solve(&self, b: &Matrix<T, N, 1>) -> Matrix<T, N, 1> {
+        let mut y = [T::zero(); N];
+        for i in 0..N {
+            y[i] = (0..i).fold(b.data[self.row_perm[i]], |acc, j| {
+                acc - self.lower[(i, j)] * y[j]
+            });
+        }
+
+        let mut z = [T::zero(); N];
+        for i in (0..N).rev() {
+            z[i] = (i + 1..N).fold(y[i], |acc, j| acc - self.upper[(i, j)] * z[j])
+                / self.upper[(i, i)];
+        }
+
+        let mut x: Matrix<T, N, 1> = Matrix::zeroes(N, 1);
+        for (&c, &e) in self.col_perm.iter().zip(z.iter()) {
+            x.data[c] = e;
+        }
+        x
+    }
+
+    /// Calculates the determinant of the decomposed matrix: the product of the diagonal
+    /// of `U`, with its sign flipped for every odd permutation.
+    pub fn determinant(&self) -> T {
+        let det = det_from_lu(&self.upper, &self.row_perm);
+        if permutation_parity(&self.col_perm) == 1 {
+            det
+        } else {
+            -det
         }
+    }
+}
+
+#[allow(clippy::identity_op)]
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    [(); N * N]:,
+    [(); N * 1]:,
+    T: Float,
+{
+    /// Calculates the LU decomposition of the matrix using partial pivoting. This is the
+    /// same as `lu_with(PivotStrategy::Partial)`.
+    pub fn lu(&self) -> Option<LuDecomposition<T, N>> {
+        self.lu_with(PivotStrategy::Partial)
+    }
+
+    /// Calculates the LU decomposition `PAQ = LU` of the matrix by Gaussian elimination,
+    /// choosing pivots according to `strategy`.
+    ///
+    /// Returns `None` if a pivot is zero. With pivoting this means the matrix is singular;
+    /// without pivoting it may also just mean that pivoting was needed. Note that a
+    /// nearly singular matrix still gets decomposed, but solving with it will amplify
+    /// rounding errors by about its condition number.
+    pub fn lu_with(&self, strategy: PivotStrategy) -> Option<LuDecomposition<T, N>> {
+        let mut a = *self;
+        let mut row_perm: [usize; N] = std::array::from_fn(|i| i);
+        let mut col_perm: [usize; N] = std::array::from_fn(|i| i);
+
+        for k in 0..N {
+            // Ties go to the first candidate, so no needless swaps are made.
+            let larger = |best: (usize, usize), candidate: (usize, usize)| {
+                if a[candidate].abs() > a[best].abs() {
+                    candidate
+                } else {
+                    best
+                }
+            };
+            let (pivot_row, pivot_col) = match strategy {
+                PivotStrategy::None => (k, k),
+                PivotStrategy::Partial => (k..N).map(|x| (x, k)).fold((k, k), larger),
+                PivotStrategy::Complete => (k..N)
+                    .flat_map(|x| (k..N).map(move |y| (x, y)))
+                    .fold((k, k), larger),
+            };
+            let pivot = a[(pivot_row, pivot_col)];
+            if pivot.is_zero() || pivot.is_nan() {
+                return None;
+            }
+
+            for y in 0..N {
+                a.swap((k, y), (pivot_row, y));
+            }
+            row_perm.swap(k, pivot_row);
+            for x in 0..N {
+                a.swap((x, k), (x, pivot_col));
+            }
+            col_perm.swap(k, pivot_col);
+
+            // The multipliers are stored in the eliminated entries, where `L` ends up.
+            for i in k + 1..N {
+                let factor = a[(i, k)] / a[(k, k)];
+                a[(i, k)] = factor;
+                for j in k + 1..N {
+                    a[(i, j)] = a[(i, j)] - factor * a[(k, j)];
+                }
+            }
+        }
+
+        Some(LuDecomposition {
+            lower: Matrix::from_fn(|x, y| match x.cmp(&y) {
+                std::cmp::Ordering::Greater => a[(x, y)],
+                std::cmp::Ordering::Equal => T::one(),
+                std::cmp::Ordering::Less => T::zero(),
+            }),
+            upper: Matrix::from_fn(|x, y| if x <= y { a[(x, y)] } else { T::zero() }),
+            row_perm,
+            col_perm,
+        })
+    }
+
+    /// Solves the system `Ax = b` through an LU decomposition with the given pivoting
+    /// strategy. Returns `None` if the decomposition fails; see `lu_with`.
+    pub fn solve_with(
+        &self,
+        b: &Matrix<T, N, 1>,
+        strategy: PivotStrategy,
+    ) -> Option<Matrix<T, N, 1>> {
+        self.lu_with(strategy).map(|lu| lu.solve(b))
+    }
+
+    /// Calculates the inverse of the matrix through an LU decomposition with the given
+    /// pivoting strategy, by solving for every column of the identity. Returns `None` if
+    /// the decomposition fails; see `lu_with`.
+    pub fn inverse_with(&self, strategy: PivotStrategy) -> Option<Self> {
+        let lu = self.lu_with(strategy)?;
+        let cols: Vec<Matrix<T, N, 1>> = (0..N)
+            .map(|j| {
+                lu.solve(&Matrix::from_fn(
+                    |x, _| if x == j { T::one() } else { T::zero() },
+                ))
+            })
+            .collect();
+        Some(Matrix::from_fn(|x, y| cols[y].data[x]))
+    }
+}
+
+#[allow(clippy::eq_op)]
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    [(); N * M]:,
+    [(); M * N]:,
+    [(); M * M]:,
+    [(); N * N]:,
+    T: Float + Signed,
+    DimensionAssertion<{ N == N }>: IsTrue,
+    DimensionAssertion<{ M == M }>: IsTrue,
+{
+    /// Calculates the orthogonal projector onto the column space of the matrix,
+    /// `A(AᵀA)⁻¹Aᵀ`. This is what least squares boils down to: the projection of `b` is
+    /// the closest vector to `b` that `Ax` can reach.
+    ///
+    /// The matrix needs to have full column rank, i.e. linearly independent columns, for
+    /// `AᵀA` to be invertible. Returns `None` otherwise.
+    pub fn projection_matrix(&self) -> Option<Matrix<T, N, N>> {
+        let transpose = self.transpose();
+        let gram_inverse = (transpose * *self).inverse()?;
+        Some(*self * (gram_inverse * transpose))
+    }
+
+    /// Projects `b` orthogonally onto the column space of the matrix. See
+    /// `projection_matrix` for the requirements.
+    #[allow(clippy::identity_op)]
+    pub fn project_onto(&self, b: &Matrix<T, N, 1>) -> Option<Matrix<T, N, 1>>
+    where
+        [(); N * 1]:,
+    {
+        Some(self.projection_matrix()? * *b)
+    }
+}
+
+// Reductions along rows and columns.
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    [(); N * M]:,
+    T: Add<Output = T> + Zero + Copy,
+{
+    /// Sums every row, giving a column vector with one entry per row.
+    #[allow(clippy::identity_op)]
+    pub fn row_sums(&self) -> Matrix<T, N, 1>
+    where
+        [(); N * 1]:,
+    {
+        Matrix::from_array(std::array::from_fn(|r| {
+            (0..M).fold(T::zero(), |acc, c| acc + self[(r, c)])
+        }))
+    }
+
+    /// Sums every column, giving a row vector with one entry per column.
+    #[allow(clippy::identity_op)]
+    pub fn col_sums(&self) -> Matrix<T, 1, M>
+    where
+        [(); 1 * M]:,
+    {
+        Matrix::from_array(std::array::from_fn(|c| {
+            (0..N).fold(T::zero(), |acc, r| acc + self[(r, c)])
+        }))
+    }
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    [(); N * M]:,
+    T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+{
+    /// Averages every row, giving a column vector with one entry per row. For integer
+    /// types the division rounds towards zero.
+    ///
+    /// ## Panics
+    ///
+    /// For integer types, if the matrix has no columns: the mean of nothing is a
+    /// division by zero. Floating-point types give NaN instead.
+    #[allow(clippy::identity_op)]
+    pub fn row_means(&self) -> Matrix<T, N, 1>
+    where
+        [(); N * 1]:,
+    {
+        // Counting with `T::one()` avoids needing a conversion from `usize`.
+        let count = (0..M).fold(T::zero(), |acc, _| acc + T::one());
+        let sums = self.row_sums();
+        Matrix::from_array(std::array::from_fn(|r| sums.data[r] / count))
+    }
 
-        Matrix {
-            data,
-            rows: self.rows,
-            cols: self.cols,
-        }
+    /// Averages every column, giving a row vector with one entry per column. For integer
+    /// types the division rounds towards zero.
+    ///
+    /// ## Panics
+    ///
+    /// For integer types, if the matrix has no rows: the mean of nothing is a
+    /// division by zero. Floating-point types give NaN instead.
+    #[allow(clippy::identity_op)]
+    pub fn col_means(&self) -> Matrix<T, 1, M>
+    where
+        [(); 1 * M]:,
+    {
+        let count = (0..N).fold(T::zero(), |acc, _| acc + T::one());
+        let sums = self.col_sums();
+        Matrix::from_array(std::array::from_fn(|c| sums.data[c] / count))
     }
 }
 
-impl<T, Q, const N: usize, const M: usize> SubAssign<Matrix<Q, N, M>> for Matrix<T, N, M>
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
 where
-    T: SubAssign<Q>,
-    Q: Copy,
     [(); N * M]:,
+    T: Eq + Hash + Clone,
 {
-    fn sub_assign(&mut self, rhs: Matrix<Q, N, M>) {
-        for i in 0..N * M {
-            self.data[i] -= rhs.data[i];
+    /// Counts how often every distinct value occurs in the matrix.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<u8, 2, 2> = Matrix::from(vec![vec![0, 1], vec![1, 1]]);
+    /// let counts = m.value_counts();
+    /// assert_eq!((counts[&0], counts[&1]), (1, 3));
+    /// ```
+    pub fn value_counts(&self) -> HashMap<T, usize> {
+        let mut counts = HashMap::new();
+        for e in self.data.iter() {
+            *counts.entry(e.clone()).or_insert(0) += 1;
         }
+        counts
     }
 }
 
-// Negation
+/// The direction in which `reduce_axis` combines entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// Combine the entries of every row, giving one value per row.
+    Rows,
+    /// Combine the entries of every column, giving one value per column.
+    Cols,
+}
 
-impl<T, const N: usize, const M: usize> Neg for Matrix<T, N, M>
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
 where
-    T: Zero + Copy + Neg<Output = T>,
     [(); N * M]:,
+    T: Copy,
 {
-    type Output = Self;
-    fn neg(self) -> Self::Output {
-        let mut data = Matrix::zeroes(N, M);
-        for x in 0..N {
-            for y in 0..M {
-                data[(x, y)] = -self[(x, y)];
-            }
+    /// Folds the entries of every row or every column with `func`, starting from `init`.
+    /// This gives N values for `Axis::Rows` and M values for `Axis::Cols`; since that
+    /// length depends on the axis, they are returned as a `Vec`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::{Axis, Matrix};
+    /// let m: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 5, 3], vec![4, 2, 6]]);
+    /// assert_eq!(m.reduce_axis(Axis::Rows, i32::MIN, i32::max), vec![5, 6]);
+    /// assert_eq!(m.reduce_axis(Axis::Cols, 1, |a, b| a * b), vec![4, 10, 18]);
+    /// ```
+    pub fn reduce_axis<F>(&self, axis: Axis, init: T, mut func: F) -> Vec<T>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        match axis {
+            Axis::Rows => (0..N)
+                .map(|r| (0..M).fold(init, |acc, c| func(acc, self[(r, c)])))
+                .collect(),
+            Axis::Cols => (0..M)
+                .map(|c| (0..N).fold(init, |acc, r| func(acc, self[(r, c)])))
+                .collect(),
         }
-
-        data
     }
 }
 
-// Multiplication
+// Element-wise functions. These apply a function to every entry separately; they are
+// not the matrix functions (like `expm`) of the same name.
 
 impl<T, const N: usize, const M: usize> Matrix<T, N, M>
 where
-    T: Copy + Mul<Output = T> + Zero,
     [(); N * M]:,
+    T: Float,
 {
-    /// Multiplies every element of a Matrix with a scalar value.
-    pub fn scalar_mul(&self, rhs: T) -> Matrix<T, N, M> {
-        let mut data: Matrix<T, N, M> = Matrix::zeroes(N, M);
+    /// Raises every entry to the power `exp`. This is not the matrix power.
+    ///
+    /// As with `f64::powf`, a negative entry raised to a non-integer power gives NaN.
+    pub fn powf(&self, exp: T) -> Matrix<T, N, M> {
+        Matrix::from_array(std::array::from_fn(|i| self.data[i].powf(exp)))
+    }
 
-        for x in 0..N {
-            for y in 0..M {
-                data[(x, y)] = self[(x, y)].mul(rhs);
-            }
-        }
+    /// Raises every entry to the integer power `exp`. This is not the matrix power.
+    pub fn powi(&self, exp: i32) -> Matrix<T, N, M> {
+        Matrix::from_array(std::array::from_fn(|i| self.data[i].powi(exp)))
+    }
+
+    /// Takes the square root of every entry. Negative entries give NaN. This is not
+    /// the matrix square root.
+    pub fn sqrt(&self) -> Matrix<T, N, M> {
+        Matrix::from_array(std::array::from_fn(|i| self.data[i].sqrt()))
+    }
+
+    /// Takes the exponential of every entry. This is not the matrix exponential; see
+    /// `expm` for that.
+    pub fn exp(&self) -> Matrix<T, N, M> {
+        Matrix::from_array(std::array::from_fn(|i| self.data[i].exp()))
+    }
 
-        data
+    /// Takes the natural logarithm of every entry. Negative entries give NaN and zeroes
+    /// give negative infinity. This is not the matrix logarithm; see `logm` for that.
+    pub fn ln(&self) -> Matrix<T, N, M> {
+        Matrix::from_array(std::array::from_fn(|i| self.data[i].ln()))
+    }
+
+    /// Takes the reciprocal of every entry. Zeroes give infinity. This is not the
+    /// matrix inverse.
+    pub fn recip(&self) -> Matrix<T, N, M> {
+        Matrix::from_array(std::array::from_fn(|i| self.data[i].recip()))
     }
 }
 
-impl<T, Q, R, const N: usize, const M: usize, const O: usize, const P: usize> Mul<Matrix<Q, O, P>>
-    for Matrix<T, N, M>
+// Norms.
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
 where
-    T: Copy + Mul<Q, Output = R>,
-    Q: Copy,
-    R: Add + Zero + Copy,
     [(); N * M]:,
-    [(); O * P]:,
-    [(); N * P]:,
-    DimensionAssertion<{ M == O }>: IsTrue,
+    T: Float,
 {
-    type Output = Matrix<R, N, P>;
-    /// Multiplies two matrices.
-    ///
-    /// ## Examples
-    ///
-    /// ```compile_fail
-    /// let m1: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
-    /// let m2: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
-    /// let a = m1 * m2; // this does not compile!
-    /// ```
-    fn mul(self, rhs: Matrix<Q, O, P>) -> Self::Output {
-        let mut result: Matrix<R, N, P> = Matrix::zeroes(N, P);
+    /// Calculates the one-norm of the matrix: the largest absolute column sum.
+    pub fn norm_one(&self) -> T {
+        (0..M)
+            .map(|c| (0..N).fold(T::zero(), |acc, r| acc + self[(r, c)].abs()))
+            .fold(T::zero(), T::max)
+    }
 
-        for x in 0..N {
-            for y in 0..P {
-                let mut dot_product_terms = [R::zero(); M];
-                for i in 0..M {
-                    dot_product_terms[i] = self[(x, i)] * rhs[(i, y)];
-                }
-                result[(x, y)] = dot_product_terms
-                    .iter()
-                    .fold(R::zero(), |acc, val| acc + *val);
-            }
-        }
+    /// Calculates the infinity-norm of the matrix: the largest absolute row sum.
+    pub fn norm_inf(&self) -> T {
+        (0..N)
+            .map(|r| (0..M).fold(T::zero(), |acc, c| acc + self[(r, c)].abs()))
+            .fold(T::zero(), T::max)
+    }
 
-        result
+    /// Calculates the Frobenius norm of the matrix: the square root of the sum of
+    /// the squares of all elements.
+    pub fn norm_frobenius(&self) -> T {
+        self.data
+            .iter()
+            .fold(T::zero(), |acc, e| acc + *e * *e)
+            .sqrt()
     }
 }
 
-impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+impl<T, const N: usize> Matrix<T, N, N>
 where
-    [(); N * M]:,
-    [(); M * N]:,
-    T: Copy + Zero,
+    [(); N * N]:,
+    T: Float + Signed,
 {
-    /// Returns the transpose of the matrix.
+    /// Estimates the condition number of the matrix with respect to the one-norm,
+    /// i.e. `‖A‖₁ ‖A⁻¹‖₁`. A value close to one means the matrix is well-conditioned;
+    /// the larger the value, the more sensitive calculations involving the matrix are
+    /// to rounding errors.
     ///
-    /// Note that this means that (unless the Matrix is square) the return type is
-    /// different from the caller type: `Matrix<u8, 2, 3>.transpose()` returns a `Matrix<u8, 3, 2>`.
-    pub fn transpose(&self) -> Matrix<T, M, N> {
-        let mut result = Matrix::zeroes(M, N);
-
-        for x in 0..M {
-            for y in 0..N {
-                result[(x, y)] = self[(y, x)];
-            }
+    /// Singular matrices have an infinite condition number.
+    pub fn condition_number(&self) -> T {
+        match self.inverse() {
+            Some(inverse) => self.norm_one() * inverse.norm_one(),
+            None => T::infinity(),
         }
-
-        result
     }
 }
 
+// Approximate comparisons and classification.
+
 impl<T, const N: usize, const M: usize> Matrix<T, N, M>
 where
     [(); N * M]:,
-    [(); (N - 1) * (M - 1)]:,
-    T: Zero + Copy,
+    T: Float,
 {
-    /// Calculates the submatrix of a matrix. The submatrix is the smaller matrix aqcuired from
-    /// ignoring the existence of one row and one column from that matrix.
-    pub fn submatrix(&self, r: usize, c: usize) -> Matrix<T, { N - 1 }, { M - 1 }> {
-        assert!(r < self.rows, "Specified out-of-bounds index in creating a submatrix: indexed row {} while matrix has {} rows.", r, self.rows);
-        assert!(c < self.cols, "Specified out-of-bounds index in creating a submatrix: indexed column {} while matrix has {} columns.", c, self.cols);
+    /// Checks if two matrices are equal up to a tolerance: every pair of corresponding
+    /// elements may differ by at most `tol`.
+    pub fn approx_eq(&self, other: &Self, tol: T) -> bool {
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .all(|(a, b)| (*a - *b).abs() <= tol)
+    }
+}
 
-        let mut subm: Matrix<T, { N - 1 }, { M - 1 }> =
-            Matrix::zeroes(self.rows - 1, self.cols - 1);
+#[allow(clippy::eq_op)]
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    [(); N * N]:,
+    T: Float,
+    DimensionAssertion<{ N == N }>: IsTrue,
+{
+    /// Checks if the trace of a square matrix is zero, up to a tolerance.
+    pub fn is_traceless(&self, tol: T) -> bool {
+        self.trace().abs() <= tol
+    }
 
-        let mut x_counter = 0usize;
-        let mut y_counter = 0usize;
+    /// Checks if a square matrix is idempotent, i.e. if `A * A = A` up to a tolerance.
+    /// Projection matrices are the typical example.
+    pub fn is_idempotent(&self, tol: T) -> bool {
+        (*self * *self).approx_eq(self, tol)
+    }
 
-        for x in 0..N {
-            if x == r {
-                continue;
-            }
-            for y in 0..M {
-                if y == c {
-                    continue;
-                }
-                subm[(x_counter, y_counter)] = self[(x, y)];
-                y_counter += 1;
-            }
-            y_counter = 0;
-            x_counter += 1;
-        }
+    /// Checks if all entries off the main diagonal are zero, up to a tolerance.
+    pub fn is_diagonal(&self, tol: T) -> bool {
+        self.iter_indexed()
+            .all(|((x, y), e)| x == y || e.abs() <= tol)
+    }
 
-        subm
+    /// Checks if the matrix is the identity, up to a tolerance: every diagonal entry
+    /// has to be within `tol` of one and every other entry within `tol` of zero. The
+    /// check stops at the first entry that doesn't match. This is useful for verifying
+    /// results like `A * A⁻¹` or `QᵀQ`.
+    pub fn is_identity(&self, tol: T) -> bool {
+        self.iter_indexed().all(|((x, y), &e)| {
+            let expected = if x == y { T::one() } else { T::zero() };
+            (e - expected).abs() <= tol
+        })
+    }
+
+    /// Checks if the matrix is strictly diagonally dominant by rows: in every row, the
+    /// absolute value of the diagonal entry is larger than the sum of the absolute
+    /// values of the other entries. Such matrices are invertible, and the Jacobi and
+    /// Gauss-Seidel methods are guaranteed to converge for them.
+    pub fn is_diagonally_dominant(&self) -> bool {
+        (0..N).all(|x| {
+            let off_diagonal = (0..N)
+                .filter(|&y| y != x)
+                .fold(T::zero(), |acc, y| acc + self[(x, y)].abs());
+            self[(x, x)].abs() > off_diagonal
+        })
     }
 }
 
+#[allow(clippy::eq_op)]
 impl<T, const N: usize> Matrix<T, N, N>
 where
     [(); N * N]:,
-    [(); (N - 1) * (N - 1)]:,
-    T: Zero + Copy,
+    T: Copy + Zero + Mul<Output = T> + PartialEq,
+    DimensionAssertion<{ N == N }>: IsTrue,
 {
-    /// Calculates the submatrix of a square matrix. This implementation is necessary for some
-    /// other calculations to be valid (e.g. the comatrix); it does not perform better at all.
-    pub fn square_submatrix(&self, r: usize, c: usize) -> Matrix<T, { N - 1 }, { N - 1 }> {
-        self.submatrix(r, c)
+    /// Checks if a square matrix is nilpotent, i.e. if `A^k = 0` for some `k`. Only
+    /// powers up to and including `max_power` are checked; note that an N by N
+    /// nilpotent matrix always satisfies `A^N = 0`.
+    pub fn is_nilpotent(&self, max_power: usize) -> bool {
+        let zero: Matrix<T, N, N> = Matrix::zeroes(N, N);
+        let mut power = *self;
+        for _ in 0..max_power {
+            if power == zero {
+                return true;
+            }
+            power = power * *self;
+        }
+        false
     }
 }
 
-impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+// Matrix functions.
+
+/// The maximum number of terms of a power series that get summed when calculating
+/// a matrix function.
+const MAX_SERIES_TERMS: usize = 64;
+/// The maximum number of iterations an iterative method gets before giving up.
+const MAX_ITERATIONS: usize = 100;
+
+#[allow(clippy::eq_op)]
+impl<T, const N: usize> Matrix<T, N, N>
 where
-    [(); N * M]:,
-    T: Zero + One + Copy + NumOps + PartialEq + PartialOrd + Signed,
+    [(); N * N]:,
+    T: Float,
+    DimensionAssertion<{ N == N }>: IsTrue,
 {
-    /// Applies an elementary row operation to a specified row.
+    /// Calculates the matrix exponential `e^A`.
     ///
-    /// This function takes a source row index and a target row index, as
-    /// well as a closure that itself takes two inputs. These two inputs act
-    /// as a placeholder for an element of the source and target row respectively
-    /// in an expression describing what happens to each element of the target row.
-    /// For example, the operation "add two times the first row to the fifth row" would
-    /// look like `matrix.row_op(0, 4, |r1, r2| 2 * r1 + r2)`.
-    ///
-    /// Note that this makes `row_op` quite a powerful function: a row effectively be
-    /// copied to another one, or set to a custom value.
-    pub fn row_op<F>(&mut self, source_row: usize, target_row: usize, func: F)
-    where
-        F: Fn(T, T) -> T,
-    {
-        assert!(
-            source_row < N,
-            "Index out of bounds: tried to access row {source_row} but matrix has {N} rows."
-        );
-        assert!(
-            target_row < N,
-            "Index out of bounds: tried to access row {target_row} but matrix has {N} rows."
-        );
-        for i in 0..M {
-            self[(target_row, i)] = func(self[(source_row, i)], self[(target_row, i)]);
-        }
-    }
-
-    /// Swaps two rows. Mutates the matrix in-place.
-    pub fn row_swap(&mut self, r1: usize, r2: usize) {
-        assert!(
-            r1 < N,
-            "Index out of bounds: tried to access row {r1} but matrix has {N} rows."
-        );
-        assert!(
-            r2 < N,
-            "Index out of bounds: tried to access row {r2} but matrix has {N} rows."
-        );
+    /// The matrix is first scaled down by a power of two until its one-norm is at most
+    /// one half, after which the Taylor series of the exponential converges quickly.
+    /// The result is then squared back up.
+    pub fn expm(&self) -> Self {
+        let two = T::one() + T::one();
+        let norm = self.norm_one();
 
-        let tmp = self.row(r1);
-        for i in 0..N {
-            self[(r1, i)] = self[(r2, i)];
-            self[(r2, i)] = tmp[i];
+        let mut scale = T::one();
+        let mut squarings = 0;
+        while norm * scale > two.recip() {
+            scale = scale / two;
+            squarings += 1;
         }
-    }
-
-    /// Mutates a matrix to be in row echelon form. The naming
-    /// convention here is slightly annoying as "row echelon form" would
-    /// be shortened to 'ref', which is obviously a reserved Rust keyword.
-    ///
-    /// Unlike most methods implemented on `Matrix`, this mutates `self` without
-    /// returning a new matrix. What it does return, however, is a product of all
-    /// row operation factors. This is used to calculate the determinant.
-    pub fn row_ef(&mut self) -> T {
-        // the accumulating factor
-        let mut accum = T::one();
 
-        if self.is_in_row_echelon_form() {
-            return accum;
+        let scaled = self.scalar_mul(scale);
+        let mut result: Matrix<T, N, N> = Matrix::identity(N);
+        let mut term: Matrix<T, N, N> = Matrix::identity(N);
+        for k in 1..=MAX_SERIES_TERMS {
+            term = (term * scaled).scalar_mul(T::from(k).unwrap().recip());
+            result = result + term;
+            if term.norm_one() <= T::epsilon() * result.norm_one() {
+                break;
+            }
         }
 
-        // We operate column by column or row by row depending on which is the bigger one
-        // but frankly, it doesn't matter. Most matrices have more columns than rows though
-        // so the variable is named column
+        for _ in 0..squarings {
+            result = result * result;
+        }
 
-        for current_column in 0..M.min(N) {
-            // If this column consists only of zeroes, it "accounts for a redundant
-            // variable" if you're speaking in terms of systems of linear equations.
-            // Hence, it can safely be ignored.
+        result
+    }
+}
 
-            if self.col(current_column).iter().all(|e| e.is_zero()) {
-                continue;
-            }
+#[allow(clippy::eq_op)]
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    [(); N * N]:,
+    T: Float + Signed,
+    DimensionAssertion<{ N == N }>: IsTrue,
+{
+    /// Calculates the principal square root of a matrix using the Denman–Beavers
+    /// iteration. Returns `None` if the iteration breaks down (a singular iterate) or
+    /// doesn't converge, which happens when the matrix has eigenvalues on the closed
+    /// negative real axis.
+    fn denman_beavers(&self) -> Option<Self> {
+        let half = (T::one() + T::one()).recip();
+        let mut y = *self;
+        let mut z: Matrix<T, N, N> = Matrix::identity(N);
+        let mut settled = false;
 
-            // To get zeroes in all rows below this one, we first calculate an
-            // intermediate row. This row is used to subtract from per row,
-            // such that the current row gets a zero.
-            //
-            // If the pivot is zero at this point, there will be a zero division
-            // in the following algorithm. However, because of the `continue` statement
-            // above, it is guaranteed that there is at least one nonzero element in
-            // this column. So, we swap the row containing that element and the current one
-            // around.
-            // Note that this shouldn't check entries in the column that come before the
-            // currently checked row! Those might be nonzero, but are irrelevant here.
+        for _ in 0..MAX_ITERATIONS {
+            let y_inverse = y.inverse()?;
+            let z_inverse = z.inverse()?;
+            let next_y = (y + z_inverse).scalar_mul(half);
+            z = (z + y_inverse).scalar_mul(half);
 
-            if self[(current_column, current_column)].is_zero() {
-                let index_of_nonzero = self
-                    .col(current_column)
-                    .iter()
-                    .enumerate()
-                    .skip(current_column) // to ignore previous possibly nonzero values
-                    .find(|(_idx, elem)| !elem.is_zero())
-                    .unwrap()
-                    .0;
+            // The iteration converges quadratically, so once the difference between two
+            // iterates is around the square root of the machine epsilon, one more step
+            // brings the error down to rounding level.
+            let difference = (next_y - y).norm_one();
+            y = next_y;
+            if settled {
+                return if y.all(|e| e.is_finite()) {
+                    Some(y)
+                } else {
+                    None
+                };
+            }
+            settled = difference <= T::epsilon().sqrt() * y.norm_one();
+        }
 
-                self.row_swap(current_column, index_of_nonzero);
-                accum = accum.neg(); // swapping two rows multiplies the determinant by negative one
+        None
+    }
+
+    /// Calculates the principal matrix square root, i.e. the matrix `X` whose eigenvalues
+    /// all have a nonnegative real part such that `X * X = A`.
+    ///
+    /// Symmetric matrices are handled through their eigendecomposition `A = VDVᵀ`, giving
+    /// `X = V√DVᵀ`; this needs all eigenvalues to be nonnegative, i.e. the matrix to be
+    /// positive semidefinite. All other matrices go through the Denman–Beavers iteration,
+    /// which needs the matrix to have no eigenvalues on the closed negative real axis.
+    ///
+    /// Returns `None` if no real principal square root exists, or if the iteration
+    /// doesn't converge.
+    pub fn sqrtm(&self) -> Option<Self> {
+        if !self.is_symmetric() {
+            return self.denman_beavers();
+        }
+
+        let (eigenvalues, eigenvectors) = self.symmetric_eigen();
+        // Eigenvalues that are only negative due to rounding errors are treated as zero.
+        let tolerance =
+            T::from(N).unwrap() * T::epsilon() * eigenvalues.first().map_or(T::zero(), |e| e.abs());
+        if eigenvalues.iter().any(|&e| e < -tolerance) {
+            return None;
+        }
+
+        let roots: Matrix<T, N, N> = Matrix::from_fn(|x, y| {
+            if x == y {
+                eigenvalues[x].max(T::zero()).sqrt()
+            } else {
+                T::zero()
             }
+        });
+        Some(eigenvectors * roots * eigenvectors.transpose())
+    }
 
-            let pivot = self[(current_column, current_column)];
+    /// Calculates the principal matrix logarithm, i.e. the matrix `X` whose eigenvalues
+    /// all have an imaginary part in `(-π, π)` such that `e^X = A`.
+    ///
+    /// This uses the inverse scaling and squaring method: square roots of the matrix
+    /// are taken until it is within a one-norm distance of 1/4 from the identity. In that
+    /// region the Taylor series of `log(I + X)` converges, and quickly so. The result is
+    /// then scaled back up by the appropriate power of two.
+    ///
+    /// Returns `None` if no real principal logarithm exists, which is the case when the
+    /// matrix is singular or has negative real eigenvalues.
+    pub fn logm(&self) -> Option<Self> {
+        // A matrix with a nonpositive determinant has either a zero eigenvalue or
+        // a negative one, so it can be ruled out immediately.
+        if self.determinant() <= T::zero() {
+            return None;
+        }
 
-            //println!("before:\n{self}");
+        let identity: Matrix<T, N, N> = Matrix::identity(N);
+        let quarter = (T::one() + T::one() + T::one() + T::one()).recip();
 
-            //println!("on column {current_column}. pivot is {pivot}");
+        let mut root = *self;
+        let mut square_roots = 0;
+        while (root - identity).norm_one() > quarter {
+            if square_roots == MAX_ITERATIONS {
+                return None;
+            }
+            root = root.denman_beavers()?;
+            square_roots += 1;
+        }
 
-            for row_below in (current_column + 1)..N {
-                let factor = self[(row_below, current_column)] / pivot;
+        // log(I + X) = X - X²/2 + X³/3 - X⁴/4 + ...
+        let x = root - identity;
+        let mut result: Matrix<T, N, N> = Matrix::zeroes(N, N);
+        let mut power = identity;
+        for k in 1..=MAX_SERIES_TERMS {
+            power = power * x;
+            let term = power.scalar_mul(T::from(k).unwrap().recip());
+            result = if k % 2 == 1 {
+                result + term
+            } else {
+                result - term
+            };
+            if term.norm_one() <= T::epsilon() * result.norm_one() {
+                break;
+            }
+        }
 
-                //println!("making a zero in row {row_below}. factor is {factor}");
+        Some(result.scalar_mul(T::from(2).unwrap().powi(square_roots as i32)))
+    }
+}
 
-                if factor.is_zero() {
-                    continue;
-                }
+// Decompositions.
 
-                let intermediate_row: Vec<T> = self
-                    .row(current_column)
-                    .iter()
-                    .map(|e| *e * factor)
-                    .collect();
+/// Calculates the dot product of columns `a` and `b` of a row-major buffer with `cols`
+/// columns.
+fn column_dot<T: Float>(data: &[T], cols: usize, a: usize, b: usize) -> T {
+    data.chunks_exact(cols)
+        .fold(T::zero(), |acc, row| acc + row[a] * row[b])
+}
 
-                //println!("intermediate row: {intermediate_row:?}");
+/// Applies the plane rotation `(c, s)` to columns `p` and `q` of a row-major buffer with
+/// `cols` columns.
+fn rotate_columns<T: Float>(data: &mut [T], cols: usize, p: usize, q: usize, c: T, s: T) {
+    for row in data.chunks_exact_mut(cols) {
+        let (a, b) = (row[p], row[q]);
+        row[p] = c * a - s * b;
+        row[q] = s * a + c * b;
+    }
+}
 
-                for i in 0..M {
-                    self[(row_below, i)] = self[(row_below, i)] - intermediate_row[i];
-                }
+/// Calculates the singular value decomposition of a row-major buffer of `rows` by `cols`
+/// elements, where `rows >= cols`, using one-sided Jacobi rotations (Hestenes' method).
+/// Pairs of columns are rotated until they are all orthogonal; their norms are then the
+/// singular values. This is slower than bidiagonalisation, but very accurate.
+///
+/// Returns `U` as a `rows` by `rows` buffer, the singular values in descending order, and
+/// `V` as a `cols` by `cols` buffer.
+fn one_sided_jacobi<T: Float>(data: &[T], rows: usize, cols: usize) -> (Vec<T>, Vec<T>, Vec<T>) {
+    let two = T::one() + T::one();
+    let mut u = data.to_vec();
+    let mut v: Vec<T> = (0..cols * cols)
+        .map(|i| {
+            if i / cols == i % cols {
+                T::one()
+            } else {
+                T::zero()
             }
+        })
+        .collect();
 
-            //println!("{self}");
+    for _ in 0..MAX_ITERATIONS {
+        let mut rotated = false;
+        for p in 0..cols {
+            for q in p + 1..cols {
+                let alpha = column_dot(&u, cols, p, p);
+                let beta = column_dot(&u, cols, q, q);
+                let gamma = column_dot(&u, cols, p, q);
+                if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
 
-            accum = accum * self[(current_column, current_column)];
+                // The rotation that zeroes the off-diagonal entry of the 2×2 Gram matrix.
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = zeta.signum() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                let c = (T::one() + t * t).sqrt().recip();
+                rotate_columns(&mut u, cols, p, q, c, c * t);
+                rotate_columns(&mut v, cols, p, q, c, c * t);
+            }
+        }
+        if !rotated {
+            break;
         }
+    }
 
-        accum
+    let norms: Vec<T> = (0..cols)
+        .map(|j| column_dot(&u, cols, j, j).sqrt())
+        .collect();
+    let mut order: Vec<usize> = (0..cols).collect();
+    order.sort_by(|&a, &b| nan_last(&norms[b], &norms[a]));
+    let tolerance =
+        T::from(rows).unwrap() * T::epsilon() * order.first().map_or(T::zero(), |&j| norms[j]);
+
+    let mut singular_values = Vec::with_capacity(cols);
+    let mut u_full = vec![T::zero(); rows * rows];
+    let mut v_sorted = vec![T::zero(); cols * cols];
+    let mut filled = vec![false; rows];
+    for (j, &o) in order.iter().enumerate() {
+        singular_values.push(norms[o]);
+        for k in 0..cols {
+            v_sorted[k * cols + j] = v[k * cols + o];
+        }
+        if norms[o] > tolerance {
+            for k in 0..rows {
+                u_full[k * rows + j] = u[k * cols + o] / norms[o];
+            }
+            filled[j] = true;
+        }
     }
 
-    /// Checks if the matrix is in row echelon form.
-    pub fn is_in_row_echelon_form(&self) -> bool {
-        if self == &Matrix::zeroes(N, M) {
-            return true;
+    // Columns of U belonging to zero singular values (and the ones beyond `cols`) aren't
+    // determined by the matrix, so they are completed to an orthonormal basis by
+    // orthogonalising the standard basis vector that is furthest from the span so far.
+    for j in 0..rows {
+        if filled[j] {
+            continue;
         }
-        let mut rows = (0..N).map(|i| self.row(i)).peekable();
-        let mut leading_zeroes = -1isize;
-        for _i in 0..N {
-            let r = rows.next().unwrap();
-            // println!(row is {r:#?}");
-            if r.iter().all(|e| e.is_zero()) {
-                // println!("all zeroes");
-                match rows.peek() {
-                    None => {
-                        // println!("and we're the last");
-                        return true;
-                    } // we are the last row
-                    Some(next_row) => {
-                        // println!("we're not the last");
-                        if next_row.iter().all(|e| e.is_zero()) {
-                            // println!("the next row is all zeroes so we continue");
-                            continue;
-                        } else {
-                            // println!("the next row is not all zeroes so no row ech form");
-                            return false;
+        let (_, w) = (0..rows)
+            .map(|k| {
+                let mut w: Vec<T> = (0..rows)
+                    .map(|i| if i == k { T::one() } else { T::zero() })
+                    .collect();
+                // Orthogonalising twice keeps rounding errors in check.
+                for _ in 0..2 {
+                    for c in (0..rows).filter(|&c| filled[c]) {
+                        let d =
+                            (0..rows).fold(T::zero(), |acc, i| acc + w[i] * u_full[i * rows + c]);
+                        for i in 0..rows {
+                            w[i] = w[i] - d * u_full[i * rows + c];
                         }
                     }
                 }
-            }
-            // the row should contain more than leading_zeroes zeroes
-            // before anything else appears in the row (it's safe to unwrap here
-            // since we checked for all-zero rows above)
-            let first_nonzero_entry = r.iter().position(|e| !e.is_zero()).unwrap() as isize;
+                (w.iter().fold(T::zero(), |acc, &e| acc + e * e).sqrt(), w)
+            })
+            .max_by(|a, b| nan_last(&a.0, &b.0))
+            .unwrap();
+        let norm = w.iter().fold(T::zero(), |acc, &e| acc + e * e).sqrt();
+        for i in 0..rows {
+            u_full[i * rows + j] = w[i] / norm;
+        }
+        filled[j] = true;
+    }
 
-            // println!("our first nonzero entry is {first_nonzero_entry}");
+    (u_full, singular_values, v_sorted)
+}
 
-            if first_nonzero_entry > leading_zeroes {
-                // println!("which is greater than our last number of leading zeroes :)");
-                leading_zeroes = first_nonzero_entry;
-            } else {
-                // println!("which is not greater than our last number of leading zeroes");
-                return false;
-            }
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    [(); N * M]:,
+    [(); N * N]:,
+    [(); M * M]:,
+    T: Float,
+{
+    /// Calculates the singular value decomposition `A = UΣVᵀ`, where `U` and `V` are
+    /// orthogonal and `Σ` is an N by M matrix that is zero except for its diagonal.
+    /// Returns `(U, singular values, Vᵀ)`.
+    ///
+    /// There are `min(N, M)` singular values, sorted in descending order. They are
+    /// calculated with one-sided Jacobi rotations, which takes at most `MAX_ITERATIONS`
+    /// (100) sweeps over all pairs of columns; in practice it converges in far fewer.
+    pub fn svd(&self) -> (Matrix<T, N, N>, Vec<T>, Matrix<T, M, M>) {
+        if N >= M {
+            let (u, singular_values, v) = one_sided_jacobi(&self.data, N, M);
+            (
+                Matrix::from_fn(|x, y| u[x * N + y]),
+                singular_values,
+                Matrix::from_fn(|x, y| v[y * M + x]),
+            )
+        } else {
+            // Decompose the transpose instead: if Aᵀ = UΣVᵀ, then A = VΣUᵀ.
+            let (u, singular_values, v) = one_sided_jacobi(&self.to_col_major(), M, N);
+            (
+                Matrix::from_fn(|x, y| v[x * N + y]),
+                singular_values,
+                Matrix::from_fn(|x, y| u[y * M + x]),
+            )
         }
+    }
 
-        true
+    /// Calculates the numerical rank of the matrix: the number of singular values larger
+    /// than `tol`. Unlike counting the pivots of the row echelon form, this isn't thrown
+    /// off by rounding errors.
+    ///
+    /// If no tolerance is given, `max(N, M) * ε * σ₁` is used, where `σ₁` is the largest
+    /// singular value.
+    pub fn numerical_rank(&self, tol: Option<T>) -> usize {
+        let (_, singular_values, _) = self.svd();
+        let largest = singular_values.first().copied().unwrap_or_else(T::zero);
+        let tol = tol.unwrap_or_else(|| T::from(N.max(M)).unwrap() * T::epsilon() * largest);
+        singular_values.iter().filter(|&&s| s > tol).count()
     }
 }
 
-impl<T, const N: usize> Matrix<T, N, N>
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
 where
+    [(); N * M]:,
     [(); N * N]:,
-    T: Zero + One + Copy + NumOps + Signed + PartialEq + PartialOrd,
-    [(); (N - 1) * (N - 1)]:,
+    T: Float,
 {
-    /// Calculates the matrix of cofactors.
-    pub fn comatrix(&self) -> Self {
-        let mut result: Matrix<T, N, N> = Matrix::zeroes(N, N);
+    /// Calculates the QR decomposition `A = QR` using Householder reflections, where `Q`
+    /// is an orthogonal N by N matrix and `R` is upper triangular. Returns `(Q, R)`.
+    ///
+    /// The diagonal entries of `R` aren't necessarily positive.
+    pub fn qr(&self) -> (Matrix<T, N, N>, Matrix<T, N, M>) {
+        let two = T::one() + T::one();
+        let mut q: Matrix<T, N, N> =
+            Matrix::from_fn(|x, y| if x == y { T::one() } else { T::zero() });
+        let mut r = *self;
 
-        for x in 0..N {
-            for y in 0..N {
-                result[(x, y)] = self.square_submatrix(x, y).determinant() * {
-                    if (x + y) % 2usize == 0 {
-                        T::one()
-                    } else {
-                        T::one().neg()
-                    }
-                };
+        for k in 0..N.saturating_sub(1).min(M) {
+            let norm = (k..N)
+                .fold(T::zero(), |acc, i| acc + r[(i, k)] * r[(i, k)])
+                .sqrt();
+            if norm.is_zero() {
+                continue;
+            }
+            // Reflecting onto -sign(r_kk) * norm avoids cancellation.
+            let alpha = if r[(k, k)] >= T::zero() { -norm } else { norm };
+            let mut v: Vec<T> = (k..N).map(|i| r[(i, k)]).collect();
+            v[0] = v[0] - alpha;
+            let v_norm_squared = v.iter().fold(T::zero(), |acc, &e| acc + e * e);
+            if v_norm_squared.is_zero() {
+                continue;
             }
-        }
 
-        result
-    }
+            // R = HR and Q = QH, where H = I - 2vvᵀ / vᵀv.
+            for j in 0..M {
+                let dot = (0..v.len()).fold(T::zero(), |acc, i| acc + v[i] * r[(k + i, j)]);
+                let factor = two * dot / v_norm_squared;
+                for i in 0..v.len() {
+                    r[(k + i, j)] = r[(k + i, j)] - factor * v[i];
+                }
+            }
+            for i in 0..N {
+                let dot = (0..v.len()).fold(T::zero(), |acc, l| acc + q[(i, k + l)] * v[l]);
+                let factor = two * dot / v_norm_squared;
+                for l in 0..v.len() {
+                    q[(i, k + l)] = q[(i, k + l)] - factor * v[l];
+                }
+            }
+            for i in k + 1..N {
+                r[(i, k)] = T::zero();
+            }
+        }
 
-    /// Calculates the adjugate (also known as the classical adjoint) of a square matrix.
-    pub fn adjugate(&self) -> Self {
-        self.comatrix().transpose()
+        (q, r)
     }
 }
 
 impl<T, const N: usize> Matrix<T, N, N>
 where
     [(); N * N]:,
-    T: Copy + NumOps + Zero + One + PartialEq + PartialOrd + Signed,
+    T: Float,
 {
-    /// Calculates the determinant of a Matrix.
-    /// Requires the relevant type to implement NumOps (Add, Sub, Mul, Div), as well
-    /// as Copy, Zero, One and Neg.
-    pub fn determinant(&self) -> T {
-        match N {
-            1 => self[(0, 0)],
-            2 => self[(0, 0)] * self[(1, 1)] - self[(0, 1)] * self[(1, 0)],
-            3 => {
-                self[(0, 0)] * self[(1, 1)] * self[(2, 2)]
-                    + self[(0, 1)] * self[(1, 2)] * self[(2, 0)]
-                    + self[(0, 2)] * self[(1, 0)] * self[(2, 1)]
-                    - self[(0, 2)] * self[(1, 1)] * self[(2, 0)]
-                    - self[(0, 1)] * self[(1, 0)] * self[(2, 2)]
-                    - self[(0, 0)] * self[(1, 2)] * self[(2, 1)]
-            }
-            _ => {
-                
+    /// Calculates the eigenvalues and eigenvectors of a symmetric matrix using the cyclic
+    /// Jacobi method: plane rotations are applied until the off-diagonal entries vanish.
+    /// Returns the eigenvalues in descending order, and a matrix whose columns are the
+    /// corresponding (orthonormal) eigenvectors.
+    ///
+    /// The matrix is assumed to be symmetric; the result is meaningless otherwise. At
+    /// most `MAX_ITERATIONS` (100) sweeps are made, though the method converges
+    /// quadratically and usually needs fewer than ten.
+    pub fn symmetric_eigen(&self) -> (Vec<T>, Matrix<T, N, N>) {
+        let two = T::one() + T::one();
+        let mut a = *self;
+        let mut v: Matrix<T, N, N> =
+            Matrix::from_fn(|x, y| if x == y { T::one() } else { T::zero() });
+        let tolerance = T::epsilon() * self.norm_frobenius();
 
-                self.clone().row_ef()
+        for _ in 0..MAX_ITERATIONS {
+            let off_diagonal = (0..N)
+                .flat_map(|p| (p + 1..N).map(move |q| (p, q)))
+                .fold(T::zero(), |acc, (p, q)| acc + a[(p, q)] * a[(p, q)])
+                .sqrt();
+            if off_diagonal <= tolerance {
+                break;
             }
-        }
-    }
 
-    /// Tries to decompose the matrix into a lower triangular and upper triangular matrix
-    /// according to Crout's method. Returns an optional tuple `Some((lower, upper))`.
-    /// The implementation is taken straight from Wikipedia:
-    /// https://en.wikipedia.org/w/index.php?title=Crout_matrix_decomposition&oldid=956132782
-    pub fn crout_decomposition(self) -> Option<(Self, Self)> {
-        let mut lower: Matrix<T, N, N> = Matrix::zeroes(N, N);
-        let mut upper: Matrix<T, N, N> = Matrix::zeroes(N, N);
+            for p in 0..N {
+                for q in p + 1..N {
+                    if a[(p, q)].is_zero() {
+                        continue;
+                    }
+                    let theta = (a[(q, q)] - a[(p, p)]) / (two * a[(p, q)]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                    let c = (t * t + T::one()).sqrt().recip();
+                    let s = t * c;
 
-        for i in 0..N {
-            upper[(i, i)] = T::one();
+                    // A = JᵀAJ and V = VJ.
+                    for k in 0..N {
+                        let (kp, kq) = (a[(k, p)], a[(k, q)]);
+                        a[(k, p)] = c * kp - s * kq;
+                        a[(k, q)] = s * kp + c * kq;
+                    }
+                    for k in 0..N {
+                        let (pk, qk) = (a[(p, k)], a[(q, k)]);
+                        a[(p, k)] = c * pk - s * qk;
+                        a[(q, k)] = s * pk + c * qk;
+                    }
+                    for k in 0..N {
+                        let (kp, kq) = (v[(k, p)], v[(k, q)]);
+                        v[(k, p)] = c * kp - s * kq;
+                        v[(k, q)] = s * kp + c * kq;
+                    }
+                }
+            }
         }
 
+        let mut order: Vec<usize> = (0..N).collect();
+        order.sort_by(|&x, &y| nan_last(&a[(y, y)], &a[(x, x)]));
+        (
+            order.iter().map(|&i| a[(i, i)]).collect(),
+            Matrix::from_fn(|x, y| v[(x, order[y])]),
+        )
+    }
+
+    /// Calculates the Cholesky decomposition `A = LLᵀ` of a symmetric positive definite
+    /// matrix, returning the lower triangular factor `L`. Only the lower triangle of the
+    /// matrix is read.
+    ///
+    /// Returns `None` if a pivot turns out to be zero or negative, i.e. if the matrix
+    /// isn't positive definite.
+    pub fn cholesky(&self) -> Option<Self> {
+        let mut l: Matrix<T, N, N> = Matrix::from_fn(|_, _| T::zero());
         for j in 0..N {
-            for i in 0..N {
-                let mut sum = T::zero();
-                for k in 0..j {
-                    sum = sum + lower[(i, k)] * upper[(k, j)];
-                }
-                lower[(i, j)] = self[(i, j)] - sum;
+            let pivot = (0..j).fold(self[(j, j)], |acc, k| acc - l[(j, k)] * l[(j, k)]);
+            if pivot <= T::zero() || pivot.is_nan() {
+                return None;
             }
-
-            for i in j..N {
-                let mut sum = T::zero();
-                for k in 0..j {
-                    sum = sum + lower[(j, k)] * upper[(k, i)];
-                }
-                if lower[(j, j)].is_zero() {
-                    // The determinant is zero, and hence this decomposition cannot be returned.
-                    return None;
-                }
-                upper[(j, i)] = (self[(j, i)] - sum) / lower[(j, j)];
+            l[(j, j)] = pivot.sqrt();
+            for i in j + 1..N {
+                let sum = (0..j).fold(self[(i, j)], |acc, k| acc - l[(i, k)] * l[(j, k)]);
+                l[(i, j)] = sum / l[(j, j)];
             }
         }
+        Some(l)
+    }
 
-        Some((lower, upper))
+    /// Checks if the matrix is symmetric and positive definite, i.e. `xᵀAx > 0` for every
+    /// nonzero `x`, by attempting a Cholesky decomposition.
+    pub fn is_positive_definite(&self) -> bool {
+        self.is_symmetric() && self.cholesky().is_some()
+    }
+
+    /// Checks if the matrix is symmetric and positive semidefinite, i.e. `xᵀAx >= 0` for
+    /// every `x`. Singular matrices make the Cholesky decomposition break down, so this
+    /// looks at the eigenvalues instead; eigenvalues that are only negative due to
+    /// rounding errors are treated as zero.
+    pub fn is_positive_semidefinite(&self) -> bool {
+        if !self.is_symmetric() {
+            return false;
+        }
+        let (eigenvalues, _) = self.symmetric_eigen();
+        let tolerance =
+            T::from(N).unwrap() * T::epsilon() * eigenvalues.first().map_or(T::zero(), |e| e.abs());
+        eigenvalues.iter().all(|&e| e >= -tolerance)
     }
 }
 
-impl<T, const N: usize> Matrix<T, N, N>
+impl<T> Matrix<T, 1, 1>
 where
-    [(); N * N]:,
-    [(); (N - 1) * (N - 1)]:,
-    T: Copy + NumOps + Zero + PartialEq + One + Signed + PartialEq + PartialOrd,
+    T: Copy,
 {
-    /// Attempts to calculate the inverse of the Matrix. Note that this is only
-    /// implemented for `Matrix<T, N, N>`, i.e. square matrices.
+    /// Returns the single entry of a 1×1 matrix, such as the result of multiplying a
+    /// row vector by a column vector.
     ///
-    /// ## Returns
+    /// ## Example
     ///
-    /// An `Option<Self>`: `None` if the matrix isn't invertible and `Some(m)` with
-    /// m being the inverted matrix.
-    pub fn inverse(&self) -> Option<Self> {
-        let det = self.determinant();
-        if det == T::zero() {
-            None
-        } else {
-            Some(self.adjugate().scalar_mul(T::one() / det))
+    /// ```
+    /// use cayley::Matrix;
+    /// let x: Matrix<i32, 1, 3> = Matrix::from(vec![1, 2, 3]);
+    /// let y: Matrix<i32, 3, 1> = Matrix::from(vec![4, 5, 6]);
+    /// assert_eq!((x * y).scalar(), 32);
+    /// ```
+    pub fn scalar(&self) -> T {
+        self.data[0]
+    }
+}
+
+impl<T> Matrix<T, 3, 1>
+where
+    T: Mul<Output = T> + Sub<Output = T> + Copy,
+{
+    /// Calculates the cross product of two 3-element column vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let x: Matrix<i32, 3, 1> = Matrix::from(vec![vec![1], vec![0], vec![0]]);
+    /// let y: Matrix<i32, 3, 1> = Matrix::from(vec![vec![0], vec![1], vec![0]]);
+    /// assert_eq!(x.cross(&y), Matrix::from(vec![vec![0], vec![0], vec![1]]));
+    /// ```
+    pub fn cross(&self, other: &Matrix<T, 3, 1>) -> Matrix<T, 3, 1> {
+        let (a, b) = (self.data, other.data);
+        Matrix {
+            data: [
+                a[1] * b[2] - a[2] * b[1],
+                a[2] * b[0] - a[0] * b[2],
+                a[0] * b[1] - a[1] * b[0],
+            ],
         }
     }
 }
 
-impl<T, const N: usize> Matrix<T, N, N>
+impl<T> Matrix<T, 2, 2>
 where
-    [(); N * N]:,
-    T: Copy + Zero + PartialEq,
+    T: Float,
 {
-    /// Checks if a square matrix is symmetric.
-    pub fn is_symmetric(&self) -> bool {
-        self.transpose() == *self
+    /// Constructs the 2D rotation matrix that rotates a vector counterclockwise
+    /// by `theta` radians.
+    pub fn rotation_2d(theta: T) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        Matrix {
+            data: [cos, -sin, sin, cos],
+        }
     }
 }
 
-impl<T, const N: usize> Matrix<T, N, N>
+impl<T> Matrix<T, 3, 3>
 where
-    [(); N * N]:,
-    T: Copy + Zero + PartialEq + Neg<Output = T>,
+    T: Float,
 {
-    /// Checks if a square matrix is skew-symmetric.
-    pub fn is_skew_symmetric(&self) -> bool {
-        self.transpose() == -*self
+    /// Constructs the 3D rotation matrix that rotates by `theta` radians about the x-axis.
+    pub fn rotation_x(theta: T) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        let (o, l) = (T::zero(), T::one());
+        Matrix {
+            data: [l, o, o, o, cos, -sin, o, sin, cos],
+        }
+    }
+
+    /// Constructs the 3D rotation matrix that rotates by `theta` radians about the y-axis.
+    pub fn rotation_y(theta: T) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        let (o, l) = (T::zero(), T::one());
+        Matrix {
+            data: [cos, o, sin, o, l, o, -sin, o, cos],
+        }
+    }
+
+    /// Constructs the 3D rotation matrix that rotates by `theta` radians about the z-axis.
+    pub fn rotation_z(theta: T) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        let (o, l) = (T::zero(), T::one());
+        Matrix {
+            data: [cos, -sin, o, sin, cos, o, o, o, l],
+        }
     }
 }
 
+mod col_major;
+pub use col_major::ColMajorMatrix;
+mod vector;
+pub use vector::Vector;
+mod view;
+pub use view::MatrixView;
+
+#[cfg(feature = "complex")]
+mod complex;
+#[cfg(feature = "complex")]
+pub use complex::{roots, Conjugate};
+#[cfg(feature = "nalgebra")]
+mod nalgebra_conversions;
+#[cfg(feature = "npy")]
+mod npy;
+#[cfg(feature = "npy")]
+pub use npy::{NpyElement, NpyError};
+#[cfg(feature = "approx")]
+mod approx_impls;
+
 mod tests;
@@ -0,0 +1,29 @@
+//! Conversions to and from `nalgebra`'s statically sized matrices. Everything in here is
+//! gated behind the `nalgebra` feature.
+
+use crate::Matrix;
+use nalgebra::{SMatrix, Scalar};
+
+impl<T, const N: usize, const M: usize> From<Matrix<T, N, M>> for SMatrix<T, N, M>
+where
+    [(); N * M]:,
+    T: Scalar,
+{
+    /// Converts into an `nalgebra` matrix. Note that `nalgebra` stores its data in
+    /// column-major order, so this reorders the elements.
+    fn from(value: Matrix<T, N, M>) -> Self {
+        SMatrix::from_row_slice(&value.data)
+    }
+}
+
+impl<T, const N: usize, const M: usize> From<SMatrix<T, N, M>> for Matrix<T, N, M>
+where
+    [(); N * M]:,
+    T: Scalar,
+{
+    /// Converts from an `nalgebra` matrix, reordering its column-major data into
+    /// 'reading order'.
+    fn from(value: SMatrix<T, N, M>) -> Self {
+        Matrix::from_array(std::array::from_fn(|i| value[(i / M, i % M)].clone()))
+    }
+}
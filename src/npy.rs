@@ -0,0 +1,245 @@
+//! Reading and writing NumPy's `.npy` format. Everything in here is gated behind the
+//! `npy` feature.
+//!
+//! The format is documented at <https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html>.
+//! In short: a magic string, a version, the length of the header, and a header containing
+//! a Python dictionary literal describing the dtype, memory order and shape, followed by
+//! the raw data.
+
+use crate::Matrix;
+use std::fmt::{self, Display};
+use std::io::{self, Read, Write};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+/// NumPy aligns the start of the data to a multiple of this many bytes.
+const ALIGNMENT: usize = 64;
+/// NumPy leaves room in the header for the first axis to grow up to this many digits.
+const GROWTH_AXIS_MAX_DIGITS: usize = 21;
+
+/// An element type that can be stored in a `.npy` file.
+pub trait NpyElement: Sized + Copy {
+    /// The NumPy dtype descriptor of this type, such as `<f8` for an `f64`.
+    const DESCR: &'static str;
+
+    /// Writes the element in little-endian byte order.
+    fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+
+    /// Reads an element in little-endian byte order.
+    fn read_from<R: Read>(reader: &mut R) -> io::Result<Self>;
+}
+
+macro_rules! impl_npy_element {
+    ($($t:ty => $descr:literal),*) => {
+        $(
+            impl NpyElement for $t {
+                const DESCR: &'static str = $descr;
+
+                fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                    writer.write_all(&self.to_le_bytes())
+                }
+
+                fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+                    let mut bytes = [0; std::mem::size_of::<$t>()];
+                    reader.read_exact(&mut bytes)?;
+                    Ok(<$t>::from_le_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_npy_element!(
+    f32 => "<f4", f64 => "<f8",
+    i8 => "|i1", i16 => "<i2", i32 => "<i4", i64 => "<i8",
+    u8 => "|u1", u16 => "<u2", u32 => "<u4", u64 => "<u8"
+);
+
+/// The ways reading a `.npy` file can fail.
+#[derive(Debug)]
+pub enum NpyError {
+    /// An I/O error occurred while reading.
+    Io(io::Error),
+    /// The data doesn't start with the `.npy` magic string.
+    NotNpy,
+    /// The file uses a format version that isn't supported.
+    UnsupportedVersion(u8, u8),
+    /// The header couldn't be parsed.
+    InvalidHeader(String),
+    /// The dtype in the file doesn't match the element type of the matrix.
+    DtypeMismatch {
+        /// The dtype descriptor of the matrix element type.
+        expected: &'static str,
+        /// The dtype descriptor in the file.
+        found: String,
+    },
+    /// The shape in the file doesn't match the dimensions of the matrix.
+    ShapeMismatch {
+        /// The dimensions of the matrix type.
+        expected: (usize, usize),
+        /// The shape in the file.
+        found: Vec<usize>,
+    },
+}
+
+impl Display for NpyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NpyError::Io(e) => write!(f, "I/O error: {e}"),
+            NpyError::NotNpy => write!(f, "not a .npy file"),
+            NpyError::UnsupportedVersion(major, minor) => {
+                write!(f, "unsupported .npy format version {major}.{minor}")
+            }
+            NpyError::InvalidHeader(reason) => write!(f, "invalid .npy header: {reason}"),
+            NpyError::DtypeMismatch { expected, found } => {
+                write!(
+                    f,
+                    "expected dtype {expected}, but the file contains {found}"
+                )
+            }
+            NpyError::ShapeMismatch { expected, found } => write!(
+                f,
+                "expected shape {expected:?}, but the file contains shape {found:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NpyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NpyError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for NpyError {
+    fn from(value: io::Error) -> Self {
+        NpyError::Io(value)
+    }
+}
+
+/// Finds the value belonging to `key` in the header dictionary, up to the next comma
+/// (or closing parenthesis, for the shape tuple).
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, NpyError> {
+    let missing = || NpyError::InvalidHeader(format!("missing key {key}"));
+    let start = header.find(&format!("'{key}':")).ok_or_else(missing)? + key.len() + 3;
+    let rest = header[start..].trim_start();
+    let end = if rest.starts_with('(') {
+        rest.find(')').ok_or_else(missing)? + 1
+    } else {
+        rest.find([',', '}']).ok_or_else(missing)?
+    };
+    Ok(rest[..end].trim())
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    [(); N * M]:,
+    T: NpyElement,
+{
+    /// Writes the matrix in NumPy's `.npy` format (version 1.0), as a C-order array
+    /// of shape `(N, M)`. The output is identical to what `numpy.save` writes.
+    pub fn to_npy<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let mut header = format!(
+            "{{'descr': '{}', 'fortran_order': False, 'shape': ({N}, {M}), }}",
+            T::DESCR
+        );
+        header.push_str(&" ".repeat(GROWTH_AXIS_MAX_DIGITS - N.to_string().len()));
+        // The magic string, version and header length take up ten bytes, and the header
+        // ends with a newline.
+        let padding = ALIGNMENT - (10 + header.len() + 1) % ALIGNMENT;
+        header.push_str(&" ".repeat(padding));
+        header.push('\n');
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[1, 0])?;
+        writer.write_all(&(header.len() as u16).to_le_bytes())?;
+        writer.write_all(header.as_bytes())?;
+        for e in self.data.iter() {
+            e.write_to(&mut writer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a matrix from NumPy's `.npy` format. The dtype has to match `T` and the
+    /// shape has to be `(N, M)`; both C-order and Fortran-order data are supported.
+    pub fn from_npy<R: Read>(mut reader: R) -> Result<Self, NpyError> {
+        let mut magic = [0; 6];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(NpyError::NotNpy);
+        }
+
+        let mut version = [0; 2];
+        reader.read_exact(&mut version)?;
+        let header_length = match version {
+            [1, 0] => {
+                let mut length = [0; 2];
+                reader.read_exact(&mut length)?;
+                u16::from_le_bytes(length) as usize
+            }
+            [2, 0] | [3, 0] => {
+                let mut length = [0; 4];
+                reader.read_exact(&mut length)?;
+                u32::from_le_bytes(length) as usize
+            }
+            [major, minor] => return Err(NpyError::UnsupportedVersion(major, minor)),
+        };
+
+        let mut header = vec![0; header_length];
+        reader.read_exact(&mut header)?;
+        let header = String::from_utf8(header)
+            .map_err(|_| NpyError::InvalidHeader("header is not valid text".to_string()))?;
+
+        let descr = header_value(&header, "descr")?.trim_matches('\'');
+        if descr != T::DESCR {
+            return Err(NpyError::DtypeMismatch {
+                expected: T::DESCR,
+                found: descr.to_string(),
+            });
+        }
+
+        let fortran_order = match header_value(&header, "fortran_order")? {
+            "False" => false,
+            "True" => true,
+            other => {
+                return Err(NpyError::InvalidHeader(format!(
+                    "invalid fortran_order {other}"
+                )))
+            }
+        };
+
+        let shape = header_value(&header, "shape")?
+            .trim_matches(|c| c == '(' || c == ')')
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                s.parse()
+                    .map_err(|_| NpyError::InvalidHeader(format!("invalid shape entry {s}")))
+            })
+            .collect::<Result<Vec<usize>, _>>()?;
+        if shape != [N, M] {
+            return Err(NpyError::ShapeMismatch {
+                expected: (N, M),
+                found: shape,
+            });
+        }
+
+        let mut data = Vec::with_capacity(N * M);
+        for _ in 0..N * M {
+            data.push(T::read_from(&mut reader)?);
+        }
+
+        // Fortran order means the data is stored column by column.
+        Ok(Matrix::from_array(std::array::from_fn(|i| {
+            if fortran_order {
+                data[(i % M) * N + i / M]
+            } else {
+                data[i]
+            }
+        })))
+    }
+}
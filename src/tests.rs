@@ -1,8 +1,12 @@
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 pub mod tests {
     use num_traits::Signed;
 
-    use crate::Matrix;
+    use crate::{
+        det_from_lu, permutation_parity, Axis, ColMajorMatrix, ColVector, InverseError, Matrix,
+        PivotStrategy, RowVector, Solution, Vector,
+    };
 
     #[test]
     fn create_identity_matrix() {
@@ -144,10 +148,10 @@ pub mod tests {
     #[test]
     #[ignore = "This function should not be able to compile."]
     fn invalid_matrix_multiplication() {
-        let m1: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
-        let m2: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+        let _m1: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let _m2: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
 
-        // let _ = m1 * m2; this errors!
+        // let _ = _m1 * _m2; this errors!
     }
 
     #[test]
@@ -240,6 +244,54 @@ pub mod tests {
             .all(|(a, b)| a.abs() - b.abs() < 1e-10));
     }
 
+    #[test]
+    fn integer_inverse() {
+        let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![2, 1], vec![1, 1]]);
+        assert_eq!(
+            m.inverse_exact(),
+            Some(Matrix::from(vec![vec![1, -1], vec![-1, 2]]))
+        );
+
+        let m: Matrix<i32, 3, 3> = Matrix::from(vec![vec![2, 3, 1], vec![1, 2, 1], vec![1, 1, 1]]);
+        let inverse = m.inverse_exact().unwrap();
+        assert_eq!(
+            inverse,
+            Matrix::from(vec![vec![1, -2, 1], vec![0, 1, -1], vec![-1, 1, 1]])
+        );
+        assert_eq!(m * inverse, Matrix::identity(3));
+
+        // unimodular, with rows permuted so that the first pivot has to be swapped out
+        let lower: Matrix<i64, 4, 4> = Matrix::from(vec![
+            vec![1, 0, 0, 0],
+            vec![2, 1, 0, 0],
+            vec![-1, 3, 1, 0],
+            vec![0, 1, -2, 1],
+        ]);
+        let upper: Matrix<i64, 4, 4> = Matrix::from(vec![
+            vec![1, 2, 0, 1],
+            vec![0, 1, -1, 0],
+            vec![0, 0, 1, 3],
+            vec![0, 0, 0, 1],
+        ]);
+        let m = (lower * upper).permute_rows(&[3, 0, 1, 2]);
+        assert_eq!(m[(0, 0)], 0);
+        assert_eq!(m.determinant().abs(), 1);
+        let inverse = m.inverse_exact().unwrap();
+        assert_eq!(m * inverse, Matrix::identity(4));
+
+        let singular: Matrix<i32, 3, 3> =
+            Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        assert_eq!(singular.inverse_exact(), None);
+
+        // invertible, but not over the integers
+        let det_two: Matrix<i32, 2, 2> = Matrix::from(vec![vec![2, 1], vec![0, 1]]);
+        assert_eq!(det_two.inverse_exact(), None);
+        let scaled: Matrix<i32, 2, 2> = Matrix::from(vec![vec![2, 0], vec![0, 2]]);
+        assert_eq!(scaled.inverse_exact(), None);
+        let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![4, 7], vec![2, 6]]);
+        assert_eq!(m.inverse_exact(), None);
+    }
+
     #[test]
     fn any_and_all() {
         let m: Matrix<u8, 4, 4> = Matrix::from_closure(4, 4, |x, y| (2 * x + 4 * y) as u8);
@@ -275,6 +327,17 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn row_swap_non_square() {
+        let mut wide: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        wide.row_swap(0, 1);
+        assert_eq!(wide, Matrix::from(vec![vec![4, 5, 6], vec![1, 2, 3]]));
+
+        let mut tall: Matrix<i32, 3, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+        tall.row_swap(0, 2);
+        assert_eq!(tall, Matrix::from(vec![vec![5, 6], vec![3, 4], vec![1, 2]]));
+    }
+
     #[test]
     fn is_row_echelon_form() {
         assert!(Matrix::<i32, 5, 5>::identity(5).is_in_row_echelon_form());
@@ -300,4 +363,1912 @@ pub mod tests {
         m.row_ef();
         assert!(m.is_in_row_echelon_form())
     }
+
+    #[test]
+    fn cross_product() {
+        let x: Matrix<i32, 3, 1> = Matrix::from(vec![vec![1], vec![0], vec![0]]);
+        let y: Matrix<i32, 3, 1> = Matrix::from(vec![vec![0], vec![1], vec![0]]);
+        let z: Matrix<i32, 3, 1> = Matrix::from(vec![vec![0], vec![0], vec![1]]);
+
+        assert_eq!(x.cross(&y), z);
+        assert_eq!(y.cross(&z), x);
+        assert_eq!(z.cross(&x), y);
+
+        // a × b = -(b × a)
+        let a: Matrix<i32, 3, 1> = Matrix::from(vec![vec![1], vec![2], vec![3]]);
+        let b: Matrix<i32, 3, 1> = Matrix::from(vec![vec![-4], vec![5], vec![6]]);
+        assert_eq!(a.cross(&b), -b.cross(&a));
+        assert_eq!(a.cross(&a), Matrix::zeroes(3, 1));
+    }
+
+    #[test]
+    fn rotations() {
+        use std::f64::consts::FRAC_PI_2;
+
+        let r: Matrix<f64, 2, 2> = Matrix::rotation_2d(FRAC_PI_2);
+        let v: Matrix<f64, 2, 1> = Matrix::from(vec![vec![1.0], vec![0.0]]);
+        let rotated = r * v;
+        assert!((rotated[(0, 0)] - 0.0).abs() < 1e-10);
+        assert!((rotated[(1, 0)] - 1.0).abs() < 1e-10);
+
+        // ∀ R: R Rᵀ = I
+        let id: Matrix<f64, 3, 3> = Matrix::identity(3);
+        for r in [
+            Matrix::rotation_x(0.7),
+            Matrix::rotation_y(0.7),
+            Matrix::rotation_z(0.7),
+        ] {
+            let product = r * r.transpose();
+            assert!(product
+                .data
+                .iter()
+                .zip(id.data.iter())
+                .all(|(a, b): (&f64, &f64)| (a - b).abs() < 1e-10));
+        }
+    }
+
+    #[test]
+    fn homogeneous_transforms() {
+        let scale: Matrix<i32, 3, 3> = Matrix::scaling(&[2, 3]);
+        let translate: Matrix<i32, 3, 3> = Matrix::translation(&[4, 5]);
+        let point: Matrix<i32, 3, 1> = Matrix::from(vec![vec![1], vec![1], vec![1]]);
+
+        // scale first, then translate
+        assert_eq!(
+            translate * scale * point,
+            Matrix::from(vec![vec![6], vec![8], vec![1]])
+        );
+        // translate first, then scale
+        assert_eq!(
+            scale * translate * point,
+            Matrix::from(vec![vec![10], vec![18], vec![1]])
+        );
+    }
+
+    #[test]
+    fn creation_from_flat_vec() {
+        let m: Matrix<i32, 2, 3> = Matrix::from(vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(m, Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+        assert_eq!((m.rows(), m.cols()), (2, 3));
+
+        let empty: Matrix<i32, 0, 3> = Matrix::from(Vec::<i32>::new());
+        assert_eq!(empty, Matrix::from_array([]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn creation_from_flat_vec_of_wrong_length() {
+        let _m: Matrix<i32, 2, 3> = Matrix::from(vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn array_round_trip() {
+        let data = [1, 2, 3, 4, 5, 6];
+        let m: Matrix<i32, 3, 2> = Matrix::from_array(data);
+        assert_eq!(m, Matrix::from(vec![vec![1, 2], vec![3, 4], vec![5, 6]]));
+        assert_eq!(m.into_array(), data);
+    }
+
+    #[test]
+    #[cfg(feature = "complex")]
+    fn conjugate_transpose() {
+        use num_complex::Complex;
+
+        let m: Matrix<Complex<f64>, 2, 2> = Matrix::from(vec![
+            vec![Complex::new(1.0, 0.0), Complex::new(2.0, 3.0)],
+            vec![Complex::new(4.0, -5.0), Complex::new(0.0, 6.0)],
+        ]);
+
+        assert_eq!(
+            m.adjoint(),
+            Matrix::from(vec![
+                vec![Complex::new(1.0, 0.0), Complex::new(4.0, 5.0)],
+                vec![Complex::new(2.0, -3.0), Complex::new(0.0, -6.0)],
+            ])
+        );
+
+        // for real matrices the adjoint is just the transpose
+        let r: Matrix<f64, 2, 3> = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+        assert_eq!(r.adjoint(), r.transpose());
+    }
+
+    #[test]
+    #[cfg(feature = "complex")]
+    fn hermitian() {
+        use num_complex::Complex;
+
+        let h: Matrix<Complex<f64>, 2, 2> = Matrix::from(vec![
+            vec![Complex::new(2.0, 0.0), Complex::new(1.0, -1.0)],
+            vec![Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)],
+        ]);
+        assert!(h.is_hermitian(1e-12));
+
+        // symmetric, but not Hermitian
+        let s: Matrix<Complex<f64>, 2, 2> = Matrix::from(vec![
+            vec![Complex::new(2.0, 0.0), Complex::new(1.0, 1.0)],
+            vec![Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)],
+        ]);
+        assert!(!s.is_hermitian(1e-12));
+    }
+
+    #[test]
+    fn tiling() {
+        let m: Matrix<i32, 1, 2> = Matrix::from(vec![vec![1, 2]]);
+        assert_eq!(
+            m.tile::<2, 2>(),
+            Matrix::from(vec![vec![1, 2, 1, 2], vec![1, 2, 1, 2]])
+        );
+
+        let m: Matrix<i32, 2, 1> = Matrix::from(vec![vec![1], vec![2]]);
+        assert_eq!(
+            m.tile::<1, 3>(),
+            Matrix::from(vec![vec![1, 1, 1], vec![2, 2, 2]])
+        );
+    }
+
+    #[test]
+    fn flips() {
+        let m: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(
+            m.flip_horizontal(),
+            Matrix::from(vec![vec![3, 2, 1], vec![6, 5, 4]])
+        );
+        assert_eq!(
+            m.flip_vertical(),
+            Matrix::from(vec![vec![4, 5, 6], vec![1, 2, 3]])
+        );
+        assert_eq!(m.flip_horizontal().flip_horizontal(), m);
+    }
+
+    #[test]
+    fn quarter_turns() {
+        let m: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(
+            m.rotate90(),
+            Matrix::from(vec![vec![4, 1], vec![5, 2], vec![6, 3]])
+        );
+        assert_eq!(m.rotate90(), m.transpose().flip_horizontal());
+        assert_eq!(m.rotate90().rotate90().rotate90().rotate90(), m);
+    }
+
+    #[test]
+    fn creation_from_iterator() {
+        let m: Matrix<i32, 2, 3> = (0..6).collect();
+        assert_eq!(m, Matrix::from(vec![vec![0, 1, 2], vec![3, 4, 5]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn creation_from_short_iterator() {
+        let _m: Matrix<i32, 2, 3> = (0..5).collect();
+    }
+
+    #[test]
+    #[should_panic]
+    fn creation_from_long_iterator() {
+        let _m: Matrix<i32, 2, 3> = (0..7).collect();
+    }
+
+    #[test]
+    fn indexed_iteration() {
+        let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(
+            m.iter_indexed().collect::<Vec<_>>(),
+            vec![((0, 0), &1), ((0, 1), &2), ((1, 0), &3), ((1, 1), &4)]
+        );
+        assert!(m.iter_indexed().all(|(pos, e)| m[pos] == *e));
+    }
+
+    #[test]
+    fn norms() {
+        let m: Matrix<f64, 2, 2> = Matrix::from(vec![vec![1.0, -2.0], vec![-3.0, 4.0]]);
+        assert_eq!(m.norm_one(), 6.0);
+        assert_eq!(m.norm_inf(), 7.0);
+        assert!((m.norm_frobenius() - 30.0_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn condition_number() {
+        let id: Matrix<f64, 3, 3> = Matrix::identity(3);
+        assert!((id.condition_number() - 1.0).abs() < 1e-12);
+
+        let near_singular: Matrix<f64, 2, 2> =
+            Matrix::from(vec![vec![1.0, 1.0], vec![1.0, 1.0 + 1e-10]]);
+        assert!(near_singular.condition_number() > 1e9);
+
+        let singular: Matrix<f64, 2, 2> = Matrix::from(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+        assert_eq!(singular.condition_number(), f64::INFINITY);
+    }
+
+    #[test]
+    fn trace() {
+        let m: Matrix<i32, 3, 3> = Matrix::from_closure(3, 3, |x, y| (3 * x + y) as i32);
+        assert_eq!(m.trace(), 12);
+        assert_eq!(Matrix::<i32, 5, 5>::identity(5).trace(), 5);
+        assert_eq!(Matrix::<i32, 0, 0>::from_array([]).trace(), 0);
+
+        // the trace is linear and invariant under transposition
+        let n: Matrix<i32, 3, 3> = Matrix::from_closure(3, 3, |x, y| (x * y) as i32 - 2);
+        assert_eq!((m + n).trace(), m.trace() + n.trace());
+        assert_eq!(m.transpose().trace(), m.trace());
+    }
+
+    #[test]
+    fn frobenius_inner_product() {
+        let a: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let b: Matrix<i32, 2, 3> = Matrix::from(vec![vec![-1, 0, 2], vec![3, 1, -2]]);
+
+        assert_eq!(a.frobenius_inner(&b), 10);
+        assert_eq!(a.frobenius_inner(&b), (a.transpose() * b).trace());
+    }
+
+    #[test]
+    fn partial_trace() {
+        // [[1, 2], [3, 4]] ⊗ [[5, 6], [7, 8]]
+        let m: Matrix<i32, 4, 4> = Matrix::from(vec![
+            vec![5, 6, 10, 12],
+            vec![7, 8, 14, 16],
+            vec![15, 18, 20, 24],
+            vec![21, 24, 28, 32],
+        ]);
+        // tr([[5, 6], [7, 8]]) * [[1, 2], [3, 4]]
+        assert_eq!(
+            m.partial_trace::<2>(),
+            Matrix::from(vec![vec![13, 26], vec![39, 52]])
+        );
+
+        // tracing out one qubit of a Bell state leaves the maximally mixed state
+        let bell: Matrix<f64, 4, 4> = Matrix::from(vec![
+            vec![0.5, 0.0, 0.0, 0.5],
+            vec![0.0, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0],
+            vec![0.5, 0.0, 0.0, 0.5],
+        ]);
+        assert_eq!(
+            bell.partial_trace::<2>(),
+            Matrix::from(vec![vec![0.5, 0.0], vec![0.0, 0.5]])
+        );
+    }
+
+    #[test]
+    fn matrix_exponential() {
+        let zero: Matrix<f64, 2, 2> = Matrix::zeroes(2, 2);
+        assert_eq!(zero.expm(), Matrix::identity(2));
+
+        let diagonal: Matrix<f64, 2, 2> = Matrix::from(vec![vec![1.0, 0.0], vec![0.0, -2.0]]);
+        let e = diagonal.expm();
+        assert!((e[(0, 0)] - 1.0_f64.exp()).abs() < 1e-12);
+        assert!((e[(1, 1)] - (-2.0_f64).exp()).abs() < 1e-12);
+        assert!(e[(0, 1)].abs() < 1e-12 && e[(1, 0)].abs() < 1e-12);
+    }
+
+    #[test]
+    fn matrix_logarithm() {
+        let a: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![0.1, 0.2, 0.0],
+            vec![-0.3, 0.05, 0.4],
+            vec![0.2, 0.1, -0.2],
+        ]);
+        let log = a.expm().logm().unwrap();
+        assert!(log
+            .data
+            .iter()
+            .zip(a.data.iter())
+            .all(|(x, y): (&f64, &f64)| (x - y).abs() < 1e-10));
+
+        let id: Matrix<f64, 2, 2> = Matrix::identity(2);
+        assert_eq!(id.logm(), Some(Matrix::zeroes(2, 2)));
+
+        // negative eigenvalues have no real logarithm
+        let negative: Matrix<f64, 2, 2> = Matrix::from(vec![vec![-1.0, 0.0], vec![0.0, 2.0]]);
+        assert_eq!(negative.logm(), None);
+        let negative: Matrix<f64, 2, 2> = Matrix::from(vec![vec![-1.0, 0.0], vec![0.0, -2.0]]);
+        assert_eq!(negative.logm(), None);
+    }
+
+    #[test]
+    fn trace_of_product() {
+        let a: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let b: Matrix<i32, 3, 2> = Matrix::from(vec![vec![7, -8], vec![9, 10], vec![-11, 12]]);
+
+        assert_eq!(a.trace_of_product(&b), (a * b).trace());
+        assert_eq!(b.trace_of_product(&a), (b * a).trace());
+    }
+
+    #[test]
+    fn matrix_vector_multiplication() {
+        let m: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let v: Matrix<i32, 3, 1> = Matrix::from(vec![vec![7], vec![-8], vec![9]]);
+
+        assert_eq!(m.mul_vec(&v), m * v);
+        assert_eq!(m.mul_vec(&v), Matrix::from(vec![vec![18], vec![42]]));
+    }
+
+    #[test]
+    fn vector_aliases() {
+        let mut c: ColVector<i32, 3> = Matrix::from(vec![vec![1], vec![2], vec![3]]);
+        let r: RowVector<i32, 3> = c.transpose();
+
+        assert_eq!((c.len(), r.len()), (3, 3));
+        assert_eq!((c[1], r[1]), (2, 2));
+        assert_eq!(c[2], c[(2, 0)]);
+        assert_eq!(r[2], r[(0, 2)]);
+
+        c[0] = 5;
+        assert_eq!(c, Matrix::from(vec![vec![5], vec![2], vec![3]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Tried indexing into element 5")]
+    fn vector_index_mut_out_of_bounds() {
+        let mut c: ColVector<i32, 3> = Matrix::from(vec![vec![1], vec![2], vec![3]]);
+        c[5] = 1;
+    }
+
+    #[test]
+    fn square_matrix_multiplication() {
+        let m1: Matrix<i32, 3, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        let m2: Matrix<i32, 3, 3> =
+            Matrix::from(vec![vec![9, -8, 7], vec![-6, 5, -4], vec![3, -2, 1]]);
+
+        assert_eq!(
+            m1 * m2,
+            Matrix::from(vec![vec![6, -4, 2], vec![24, -19, 14], vec![42, -34, 26]])
+        );
+        assert_eq!(m1 * Matrix::<i32, 3, 3>::identity(3), m1);
+    }
+
+    #[test]
+    fn fallible_inverse() {
+        let m: Matrix<f64, 2, 2> = Matrix::from(vec![vec![4.0, 7.0], vec![2.0, 6.0]]);
+        let inverse = m.try_inverse().unwrap();
+        let expected: Matrix<f64, 2, 2> = Matrix::from(vec![vec![0.6, -0.7], vec![-0.2, 0.4]]);
+        assert!(inverse
+            .data
+            .iter()
+            .zip(expected.data.iter())
+            .all(|(a, b): (&f64, &f64)| (a - b).abs() < 1e-12));
+
+        let singular: Matrix<f64, 2, 2> = Matrix::from(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+        assert_eq!(singular.try_inverse(), Err(InverseError::Singular));
+
+        let ill_conditioned: Matrix<f64, 2, 2> =
+            Matrix::from(vec![vec![1.0, 1.0], vec![1.0, 1.0 + f64::EPSILON]]);
+        assert_eq!(
+            ill_conditioned.try_inverse(),
+            Err(InverseError::IllConditioned)
+        );
+
+        let nan: Matrix<f64, 2, 2> = Matrix::from(vec![vec![1.0, 2.0], vec![f64::NAN, 4.0]]);
+        assert_eq!(nan.try_inverse(), Err(InverseError::NonFinite));
+        let infinite: Matrix<f64, 2, 2> =
+            Matrix::from(vec![vec![1.0, f64::INFINITY], vec![3.0, 4.0]]);
+        assert_eq!(infinite.try_inverse(), Err(InverseError::NonFinite));
+    }
+
+    #[test]
+    fn element_swap() {
+        let mut m: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        m.swap((0, 1), (1, 2));
+        assert_eq!(m, Matrix::from(vec![vec![1, 6, 3], vec![4, 5, 2]]));
+
+        m.swap((1, 1), (1, 1));
+        assert_eq!(m, Matrix::from(vec![vec![1, 6, 3], vec![4, 5, 2]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds_element_swap() {
+        let mut m: Matrix<i32, 2, 3> = Matrix::zeroes(2, 3);
+        m.swap((0, 0), (2, 0));
+    }
+
+    #[test]
+    fn quick_spectral_checks() {
+        let traceless: Matrix<f64, 2, 2> = Matrix::from(vec![vec![1.0, 2.0], vec![3.0, -1.0]]);
+        assert!(traceless.is_traceless(1e-12));
+        assert!(!Matrix::<f64, 2, 2>::identity(2).is_traceless(1e-12));
+
+        let projection: Matrix<f64, 2, 2> = Matrix::from(vec![vec![0.5, 0.5], vec![0.5, 0.5]]);
+        assert!(projection.is_idempotent(1e-12));
+        assert!(!traceless.is_idempotent(1e-12));
+
+        let strictly_upper: Matrix<i32, 3, 3> =
+            Matrix::from(vec![vec![0, 1, 2], vec![0, 0, 3], vec![0, 0, 0]]);
+        assert!(strictly_upper.is_nilpotent(3));
+        assert!(!strictly_upper.is_nilpotent(2));
+        assert!(!Matrix::<i32, 3, 3>::identity(3).is_nilpotent(10));
+    }
+
+    #[test]
+    fn reduced_row_echelon_form() {
+        let m: Matrix<f64, 2, 3> = Matrix::from(vec![vec![2.0, 4.0, 6.0], vec![1.0, 3.0, 4.0]]);
+        assert!(m.rref().approx_eq(
+            &Matrix::from(vec![vec![1.0, 0.0, 1.0], vec![0.0, 1.0, 1.0]]),
+            1e-12
+        ));
+
+        // NaN can't be compared, but it mustn't make the pivot search panic either
+        let nan: Matrix<f64, 2, 2> = Matrix::from(vec![vec![1.0, 2.0], vec![f64::NAN, 4.0]]);
+        assert!(nan.rref().data.iter().any(|e| e.is_nan()));
+    }
+
+    #[test]
+    fn general_linear_systems() {
+        let a: Matrix<f64, 3, 2> =
+            Matrix::from(vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![1.0, 1.0]]);
+
+        let consistent: Matrix<f64, 3, 1> = Matrix::from(vec![1.0, 2.0, 3.0]);
+        match a.solve_general(&consistent) {
+            Solution::Unique(x) => {
+                assert!(x.approx_eq(&Matrix::from(vec![1.0, 2.0]), 1e-12))
+            }
+            other => panic!("expected a unique solution, got {other:?}"),
+        }
+
+        let inconsistent: Matrix<f64, 3, 1> = Matrix::from(vec![1.0, 2.0, 4.0]);
+        assert_eq!(a.solve_general(&inconsistent), Solution::Inconsistent);
+
+        let underdetermined: Matrix<f64, 2, 3> =
+            Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![0.0, 1.0, 1.0]]);
+        let b: Matrix<f64, 2, 1> = Matrix::from(vec![6.0, 2.0]);
+        match underdetermined.solve_general(&b) {
+            Solution::Infinite {
+                particular,
+                null_space,
+            } => {
+                assert!(particular.approx_eq(&Matrix::from(vec![2.0, 2.0, 0.0]), 1e-12));
+                assert_eq!(null_space.len(), 1);
+                assert!(null_space[0].approx_eq(&Matrix::from(vec![-1.0, -1.0, 1.0]), 1e-12));
+                assert!((underdetermined * null_space[0]).approx_eq(&Matrix::zeroes(2, 1), 1e-12));
+            }
+            other => panic!("expected infinitely many solutions, got {other:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "nalgebra")]
+    fn nalgebra_round_trip() {
+        let m: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let n: nalgebra::SMatrix<i32, 2, 3> = m.into();
+
+        assert_eq!(n, nalgebra::Matrix2x3::new(1, 2, 3, 4, 5, 6));
+        assert_eq!(n[(1, 0)], m[(1, 0)]);
+        assert_eq!(Matrix::from(n), m);
+    }
+
+    #[test]
+    fn slice_conversions() {
+        fn sum(values: impl AsRef<[i32]>) -> i32 {
+            values.as_ref().iter().sum()
+        }
+
+        let mut m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(sum(m), 10);
+        assert_eq!(m.as_ref(), &[1, 2, 3, 4]);
+
+        m.as_mut()[3] = 10;
+        assert_eq!(m[(1, 1)], 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn creation_from_too_many_rows() {
+        let _m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn creation_from_too_many_columns() {
+        let _m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn creation_from_ragged_rows() {
+        let _m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let m: Matrix<i8, 2, 2> = Matrix::from(vec![vec![100, 100], vec![1, 2]]);
+        let v: Matrix<i8, 2, 1> = Matrix::from(vec![1, 1]);
+
+        // 100 + 100 doesn't fit in an i8
+        assert_eq!(m.checked_mul(&v), None);
+        assert_eq!(m.checked_add(&m), None);
+
+        let small: Matrix<i8, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(small.checked_mul(&small), Some(small * small));
+        assert_eq!(small.checked_add(&small), Some(small + small));
+    }
+
+    #[test]
+    fn saturating_and_wrapping_arithmetic() {
+        let m: Matrix<i8, 1, 2> = Matrix::from(vec![vec![100, -100]]);
+        let n: Matrix<i8, 1, 2> = Matrix::from(vec![vec![100, -100]]);
+
+        assert_eq!(
+            m.saturating_add(&n),
+            Matrix::from(vec![vec![i8::MAX, i8::MIN]])
+        );
+        assert_eq!(m.wrapping_add(&n), Matrix::from(vec![vec![-56, 56]]));
+
+        let v: Matrix<i8, 2, 1> = Matrix::from(vec![2, -2]);
+        // 100 * 2 + (-100) * (-2) = 400
+        assert_eq!(m.saturating_mul(&v), Matrix::from(vec![vec![i8::MAX]]));
+        assert_eq!(m.wrapping_mul(&v), Matrix::from(vec![vec![400_i32 as i8]]));
+    }
+
+    #[test]
+    #[cfg(feature = "npy")]
+    fn npy_round_trip() {
+        let m: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, -2, 3], vec![4, 5, -6]]);
+        let mut buffer = Vec::new();
+        m.to_npy(&mut buffer).unwrap();
+
+        // the data starts at an aligned offset
+        assert_eq!((buffer.len() - 6 * 4) % 64, 0);
+        assert_eq!(Matrix::from_npy(buffer.as_slice()).unwrap(), m);
+
+        // wrong element type and wrong shape are both rejected
+        assert!(matches!(
+            Matrix::<f64, 2, 3>::from_npy(buffer.as_slice()),
+            Err(crate::NpyError::DtypeMismatch { .. })
+        ));
+        assert!(matches!(
+            Matrix::<i32, 3, 2>::from_npy(buffer.as_slice()),
+            Err(crate::NpyError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "npy")]
+    fn npy_from_numpy() {
+        // numpy.save(f, numpy.array([[1.5, 2.0, -3.0], [4.0, 5.25, 6.0]]))
+        let mut file = b"\x93NUMPY\x01\x00v\x00{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }                                                          \n".to_vec();
+        for e in [1.5_f64, 2.0, -3.0, 4.0, 5.25, 6.0] {
+            file.extend_from_slice(&e.to_le_bytes());
+        }
+
+        let m: Matrix<f64, 2, 3> = Matrix::from_npy(file.as_slice()).unwrap();
+        assert_eq!(
+            m,
+            Matrix::from(vec![vec![1.5, 2.0, -3.0], vec![4.0, 5.25, 6.0]])
+        );
+
+        let mut written = Vec::new();
+        m.to_npy(&mut written).unwrap();
+        assert_eq!(written, file);
+
+        // numpy.save(f, numpy.asfortranarray([[1, 2], [3, 4]], dtype=numpy.uint8))
+        let mut file = b"\x93NUMPY\x01\x00v\x00{'descr': '|u1', 'fortran_order': True, 'shape': (2, 2), }                                                           \n".to_vec();
+        file.extend_from_slice(&[1, 3, 2, 4]);
+        let m: Matrix<u8, 2, 2> = Matrix::from_npy(file.as_slice()).unwrap();
+        assert_eq!(m, Matrix::from(vec![vec![1, 2], vec![3, 4]]));
+    }
+
+    #[test]
+    fn elementwise_comparisons() {
+        let a: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 5], vec![3, -2]]);
+        let b: Matrix<i32, 2, 2> = Matrix::from(vec![vec![2, 5], vec![0, -1]]);
+
+        assert_eq!(
+            a.gt(&b),
+            Matrix::from(vec![vec![false, false], vec![true, false]])
+        );
+        assert_eq!(
+            a.lt(&b),
+            Matrix::from(vec![vec![true, false], vec![false, true]])
+        );
+        assert_eq!(
+            a.ge(&b),
+            Matrix::from(vec![vec![false, true], vec![true, false]])
+        );
+        assert_eq!(
+            a.le(&b),
+            Matrix::from(vec![vec![true, true], vec![false, true]])
+        );
+        assert_eq!(
+            a.eq_elem(&b),
+            Matrix::from(vec![vec![false, true], vec![false, false]])
+        );
+    }
+
+    #[test]
+    fn select_with_mask() {
+        let a: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+        let b: Matrix<i32, 2, 2> = Matrix::from(vec![vec![10, 20], vec![30, 40]]);
+        let mask = Matrix::from(vec![vec![true, false], vec![false, true]]);
+
+        assert_eq!(
+            a.select(&mask, &b),
+            Matrix::from(vec![vec![1, 20], vec![30, 4]])
+        );
+        assert_eq!(
+            b.select(&mask, &a),
+            Matrix::from(vec![vec![10, 2], vec![3, 40]])
+        );
+    }
+
+    #[test]
+    fn from_fn_without_zero() {
+        let m: Matrix<String, 2, 2> = Matrix::from_fn(|x, y| format!("{x},{y}"));
+        assert_eq!(m[(0, 0)], "0,0");
+        assert_eq!(m[(1, 0)], "1,0");
+        assert_eq!(m[(1, 1)], "1,1");
+
+        let mut count = 0;
+        let n: Matrix<i32, 2, 3> = Matrix::from_fn(|_, _| {
+            count += 1;
+            count
+        });
+        assert_eq!(n, Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+    }
+
+    #[test]
+    fn permutation_parities() {
+        assert_eq!(permutation_parity(&[0, 1, 2, 3]), 1);
+        assert_eq!(permutation_parity(&[1, 0, 2, 3]), -1);
+        assert_eq!(permutation_parity(&[1, 2, 0]), 1);
+        assert_eq!(permutation_parity(&[]), 1);
+
+        // swapping the rows of [[0, 2], [3, 4]] gives L = I and U = [[3, 4], [0, 2]]
+        let upper: Matrix<i32, 2, 2> = Matrix::from(vec![vec![3, 4], vec![0, 2]]);
+        let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![0, 2], vec![3, 4]]);
+        assert_eq!(det_from_lu(&upper, &[1, 0]), m.determinant());
+    }
+
+    #[test]
+    fn row_and_col_major_export() {
+        let m: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(m.to_row_major(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(m.to_col_major(), vec![1, 4, 2, 5, 3, 6]);
+        assert_eq!(m.to_col_major(), m.transpose().to_row_major());
+    }
+
+    #[test]
+    fn col_major_storage() {
+        let m: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let c = ColMajorMatrix::from(m);
+        assert_eq!(c.data, [1, 4, 2, 5, 3, 6]);
+        for x in 0..2 {
+            for y in 0..3 {
+                assert_eq!(c[(x, y)], m[(x, y)]);
+            }
+        }
+        assert_eq!(c.col_slice(1), &[2, 5]);
+        assert_eq!(Matrix::from(c), m);
+        assert_eq!(c.transpose(), m.transpose());
+
+        let n: Matrix<i32, 3, 2> = Matrix::from(vec![vec![7, 8], vec![9, 10], vec![11, 12]]);
+        assert_eq!(m * ColMajorMatrix::from(n), m * n);
+    }
+
+    #[test]
+    fn roll_rows_and_cols() {
+        let m: Matrix<i32, 3, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+
+        assert_eq!(
+            m.roll_rows(1),
+            Matrix::from(vec![vec![7, 8, 9], vec![1, 2, 3], vec![4, 5, 6]])
+        );
+        assert_eq!(
+            m.roll_rows(-1),
+            Matrix::from(vec![vec![4, 5, 6], vec![7, 8, 9], vec![1, 2, 3]])
+        );
+        assert_eq!(
+            m.roll_cols(1),
+            Matrix::from(vec![vec![3, 1, 2], vec![6, 4, 5], vec![9, 7, 8]])
+        );
+        assert_eq!(m.roll_rows(3), m);
+        assert_eq!(m.roll_cols(-4), m.roll_cols(-1));
+
+        // there is nothing to roll along an empty dimension
+        let wide: Matrix<i32, 0, 2> = Matrix::from_array([]);
+        assert_eq!(wide.roll_rows(1), wide);
+        let tall: Matrix<i32, 2, 0> = Matrix::from_array([]);
+        assert_eq!(tall.roll_cols(-1), tall);
+    }
+
+    #[test]
+    fn elementwise_powers() {
+        let m: Matrix<f64, 2, 2> = Matrix::from(vec![vec![1.0, -2.0], vec![3.0, 0.5]]);
+        let squared = m.powi(2);
+        assert_eq!(squared, Matrix::from(vec![vec![1.0, 4.0], vec![9.0, 0.25]]));
+        assert_eq!(m.powf(2.0), squared);
+        assert!(squared
+            .powf(0.5)
+            .approx_eq(&Matrix::from(vec![vec![1.0, 2.0], vec![3.0, 0.5]]), 1e-12));
+
+        // negative bases with fractional exponents give NaN
+        assert!(m.powf(0.5)[(0, 1)].is_nan());
+    }
+
+    #[test]
+    fn elementwise_math_functions() {
+        let m: Matrix<f64, 2, 2> = Matrix::from(vec![vec![4.0, 0.25], vec![9.0, 2.0]]);
+        assert!(m.sqrt().approx_eq(
+            &Matrix::from(vec![vec![2.0, 0.5], vec![3.0, 2.0_f64.sqrt()]]),
+            1e-12
+        ));
+        assert_eq!(
+            m.recip(),
+            Matrix::from(vec![vec![0.25, 4.0], vec![1.0 / 9.0, 0.5]])
+        );
+        assert!(m.ln().exp().approx_eq(&m, 1e-12));
+    }
+
+    #[test]
+    fn trace_normalize() {
+        let m: Matrix<f64, 2, 2> = Matrix::from(vec![vec![3.0, 1.0], vec![1.0, 1.0]]);
+        let normalized = m.trace_normalize().unwrap();
+        assert!((normalized.trace() - 1.0_f64).abs() < 1e-12);
+        assert_eq!(normalized[(0, 1)], 0.25);
+
+        let traceless: Matrix<f64, 2, 2> = Matrix::from(vec![vec![1.0, 2.0], vec![3.0, -1.0]]);
+        assert_eq!(traceless.trace_normalize(), None);
+    }
+
+    #[test]
+    fn from_rows_and_cols() {
+        let rows: Matrix<i32, 2, 3> = Matrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(rows, Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+
+        let cols: Matrix<i32, 3, 2> = Matrix::from_cols([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(cols, rows.transpose());
+        assert_eq!(cols.col(1), [4, 5, 6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_cols_wrong_count() {
+        let _: Matrix<i32, 2, 2> = Matrix::from_cols(vec![[1, 2]]);
+    }
+
+    #[test]
+    fn map_rows_and_cols() {
+        let m: Matrix<f64, 2, 3> = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![4.0, 8.0, 12.0]]);
+
+        let centered = m.map_rows(|row| {
+            let mean = row.iter().sum::<f64>() / row.len() as f64;
+            [row[0] - mean, row[1] - mean, row[2] - mean]
+        });
+        assert_eq!(
+            centered,
+            Matrix::from(vec![vec![-1.0, 0.0, 1.0], vec![-4.0, 0.0, 4.0]])
+        );
+
+        let scaled = m.map_cols(|col| [col[0] / col[0], col[1] / col[0]]);
+        assert_eq!(
+            scaled,
+            Matrix::from(vec![vec![1.0, 1.0, 1.0], vec![4.0, 4.0, 4.0]])
+        );
+
+        // the closure still sees every (empty) row
+        let empty_rows: Matrix<f64, 2, 0> = Matrix::from_array([]);
+        let calls = std::cell::Cell::new(0);
+        let mapped = empty_rows.map_rows(|row| {
+            calls.set(calls.get() + 1);
+            assert!(row.is_empty());
+            []
+        });
+        assert_eq!((mapped, calls.get()), (empty_rows, 2));
+
+        // and likewise every empty column
+        let empty_cols: Matrix<f64, 0, 2> = Matrix::from_array([]);
+        calls.set(0);
+        let mapped = empty_cols.map_cols(|col| {
+            calls.set(calls.get() + 1);
+            assert!(col.is_empty());
+            []
+        });
+        assert_eq!((mapped, calls.get()), (empty_cols, 2));
+
+        // with nothing to map, the closure is never called
+        assert_eq!(empty_cols.map_rows(|_| unreachable!()), empty_cols);
+        assert_eq!(empty_rows.map_cols(|_| unreachable!()), empty_rows);
+    }
+
+    #[test]
+    fn row_and_col_reductions() {
+        let m: Matrix<f64, 2, 3> = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![4.0, 6.0, 8.0]]);
+        assert_eq!(m.row_sums(), Matrix::from(vec![vec![6.0], vec![18.0]]));
+        assert_eq!(m.col_sums(), Matrix::from(vec![vec![5.0, 8.0, 11.0]]));
+        assert_eq!(m.row_means(), Matrix::from(vec![vec![2.0], vec![6.0]]));
+        assert_eq!(m.col_means(), Matrix::from(vec![vec![2.5, 4.0, 5.5]]));
+
+        let no_cols: Matrix<f64, 2, 0> = Matrix::from_array([]);
+        assert!(no_cols.row_means().data.iter().all(|e| e.is_nan()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn integer_means_of_nothing() {
+        let no_rows: Matrix<i32, 0, 2> = Matrix::from_array([]);
+        let _means = no_rows.col_means();
+    }
+
+    #[test]
+    fn tridiagonal_constructor() {
+        // the second-difference matrix
+        let m: Matrix<i32, 4, 4> = Matrix::tridiagonal(&[-1; 3], &[2; 4], &[-1; 3]);
+        assert_eq!(m[(0, 0)], 2);
+        assert_eq!(m[(1, 0)], -1);
+        assert_eq!(m[(2, 3)], -1);
+        assert_eq!(m[(0, 2)], 0);
+        assert_eq!(m[(3, 0)], 0);
+        assert!(m.is_symmetric());
+
+        let empty: Matrix<f64, 0, 0> = Matrix::tridiagonal(&[], &[], &[]);
+        assert_eq!(empty, Matrix::from_array([]));
+    }
+
+    #[test]
+    fn solve_tridiagonal_system() {
+        let m: Matrix<f64, 4, 4> = Matrix::tridiagonal(&[-1.0; 3], &[4.0; 4], &[-2.0; 3]);
+        let b: Matrix<f64, 4, 1> = Matrix::from(vec![vec![1.0], vec![2.0], vec![-3.0], vec![0.5]]);
+
+        let thomas = m.solve_tridiagonal(&b).unwrap();
+        let general = m.solve(&b).unwrap();
+        assert!(thomas.approx_eq(&general, 1e-12));
+        assert!((m * thomas).approx_eq(&b, 1e-12));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn solve_tridiagonal_rejects_dense() {
+        let m: Matrix<f64, 3, 3> = Matrix::ones(3, 3);
+        let _ = m.solve_tridiagonal(&Matrix::ones(3, 1));
+    }
+
+    #[test]
+    // The matrices here are `Copy`, so clippy would rather they were used by value.
+    #[allow(clippy::op_ref)]
+    fn arithmetic_by_reference() {
+        let a: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+        let b: Matrix<i32, 2, 2> = Matrix::from(vec![vec![0, 1], vec![1, 0]]);
+
+        assert_eq!(&a * &b, Matrix::from(vec![vec![2, 1], vec![4, 3]]));
+        assert_eq!(&a + &b, Matrix::from(vec![vec![1, 3], vec![4, 4]]));
+
+        let mut c = a;
+        c += &b;
+        assert_eq!(c, &a + &b);
+
+        // the operands are still usable
+        assert_eq!(a * b, Matrix::from(vec![vec![2, 1], vec![4, 3]]));
+    }
+
+    #[test]
+    fn resize_pad_and_crop() {
+        let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+
+        let grown: Matrix<i32, 3, 3> = m.resize(0);
+        assert_eq!(
+            grown,
+            Matrix::from(vec![vec![1, 2, 0], vec![3, 4, 0], vec![0, 0, 0]])
+        );
+
+        let shrunk: Matrix<i32, 1, 2> = m.resize(0);
+        assert_eq!(shrunk, Matrix::from(vec![vec![1, 2]]));
+        assert_eq!(grown.resize::<2, 2>(7), m);
+    }
+
+    #[test]
+    fn singular_value_decomposition() {
+        let tall: Matrix<f64, 3, 2> =
+            Matrix::from(vec![vec![3.0, 2.0], vec![2.0, 3.0], vec![2.0, -2.0]]);
+        let (u, singular_values, vt) = tall.svd();
+        assert!((singular_values[0] - 5.0_f64).abs() < 1e-12);
+        assert!((singular_values[1] - 3.0_f64).abs() < 1e-12);
+
+        let sigma: Matrix<f64, 3, 2> =
+            Matrix::from_fn(|x, y| if x == y { singular_values[x] } else { 0.0 });
+        assert!((u * sigma * vt).approx_eq(&tall, 1e-12));
+        assert!((u.transpose() * u).approx_eq(&Matrix::identity(3), 1e-12));
+        assert!((vt * vt.transpose()).approx_eq(&Matrix::identity(2), 1e-12));
+
+        // wide and rank-deficient
+        let wide: Matrix<f64, 2, 3> = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![2.0, 4.0, 6.0]]);
+        let (u, singular_values, vt) = wide.svd();
+        assert!(singular_values[0] >= singular_values[1]);
+        assert!(singular_values[1].abs() < 1e-12);
+
+        let sigma: Matrix<f64, 2, 3> =
+            Matrix::from_fn(|x, y| if x == y { singular_values[x] } else { 0.0 });
+        assert!((u * sigma * vt).approx_eq(&wide, 1e-12));
+        assert!((u.transpose() * u).approx_eq(&Matrix::identity(2), 1e-12));
+        assert!((vt * vt.transpose()).approx_eq(&Matrix::identity(3), 1e-12));
+
+        // NaN poisons the result, but sorting the singular values mustn't panic
+        let nan: Matrix<f64, 2, 2> = Matrix::from(vec![vec![1.0, 2.0], vec![f64::NAN, 4.0]]);
+        let (_, singular_values, _) = nan.svd();
+        assert!(singular_values.iter().any(|s| s.is_nan()));
+    }
+
+    #[test]
+    fn numerical_rank() {
+        // the third row is the sum of the first two, up to rounding
+        let m: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![0.1, 0.2, 0.3],
+            vec![0.7, 0.5, 0.1],
+            vec![0.1 + 0.7, 0.2 + 0.5, 0.3 + 0.1],
+        ]);
+        assert_eq!(m.numerical_rank(None), 2);
+        assert_eq!(m.numerical_rank(Some(0.0)), 3);
+
+        let identity: Matrix<f64, 3, 3> = Matrix::identity(3);
+        assert_eq!(identity.numerical_rank(None), 3);
+        let zero: Matrix<f64, 2, 3> = Matrix::zeroes(2, 3);
+        assert_eq!(zero.numerical_rank(None), 0);
+    }
+
+    #[test]
+    fn column_space_projection() {
+        let a: Matrix<f64, 3, 2> =
+            Matrix::from(vec![vec![1.0, 0.0], vec![1.0, 1.0], vec![1.0, 2.0]]);
+        let p = a.projection_matrix().unwrap();
+
+        assert!((p * p).approx_eq(&p, 1e-12));
+        assert!(p.approx_eq(&p.transpose(), 1e-12));
+        // the columns of a are left alone
+        assert!((p * a).approx_eq(&a, 1e-12));
+
+        // the residual is orthogonal to the column space
+        let b: Matrix<f64, 3, 1> = Matrix::from(vec![vec![6.0], vec![0.0], vec![0.0]]);
+        let residual = b - a.project_onto(&b).unwrap();
+        assert!((a.transpose() * residual).approx_eq(&Matrix::zeroes(2, 1), 1e-12));
+
+        let dependent: Matrix<f64, 3, 2> =
+            Matrix::from(vec![vec![1.0, 2.0], vec![1.0, 2.0], vec![1.0, 2.0]]);
+        assert_eq!(dependent.projection_matrix(), None);
+    }
+
+    #[test]
+    fn zero_and_one_traits() {
+        use num_traits::{One, Zero};
+
+        let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(Matrix::zero() + m, m);
+        assert_eq!(Matrix::<i32, 2, 2>::one() * m, m);
+        assert!(Matrix::<i32, 2, 3>::zero().is_zero());
+        assert!(!m.is_zero());
+        assert!(Matrix::<i32, 3, 3>::one().is_one());
+    }
+
+    #[test]
+    fn qr_decomposition() {
+        let m: Matrix<f64, 3, 2> =
+            Matrix::from(vec![vec![12.0, -51.0], vec![6.0, 167.0], vec![-4.0, 24.0]]);
+        let (q, r) = m.qr();
+        assert!((q * r).approx_eq(&m, 1e-12));
+        assert!((q.transpose() * q).approx_eq(&Matrix::identity(3), 1e-12));
+        assert_eq!(r[(1, 0)], 0.0);
+        assert_eq!(r[(2, 0)], 0.0);
+        assert_eq!(r[(2, 1)], 0.0);
+    }
+
+    #[test]
+    fn symmetric_eigendecomposition() {
+        let m: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![2.0, -1.0, 0.0],
+            vec![-1.0, 2.0, -1.0],
+            vec![0.0, -1.0, 2.0],
+        ]);
+        let (eigenvalues, eigenvectors) = m.symmetric_eigen();
+
+        let expected = [2.0 + 2.0_f64.sqrt(), 2.0, 2.0 - 2.0_f64.sqrt()];
+        for (e, x) in eigenvalues.iter().zip(expected) {
+            assert!((e - x).abs() < 1e-12);
+        }
+
+        let d: Matrix<f64, 3, 3> =
+            Matrix::from_fn(|x, y| if x == y { eigenvalues[x] } else { 0.0 });
+        assert!((eigenvectors * d * eigenvectors.transpose()).approx_eq(&m, 1e-12));
+
+        let nan: Matrix<f64, 2, 2> = Matrix::from(vec![vec![f64::NAN, 1.0], vec![1.0, 2.0]]);
+        let (eigenvalues, _) = nan.symmetric_eigen();
+        assert!(eigenvalues.iter().any(|e| e.is_nan()));
+    }
+
+    #[test]
+    #[cfg(feature = "complex")]
+    fn eigenvalues_symmetric_and_general() {
+        use num_complex::Complex;
+
+        let symmetric: Matrix<f64, 2, 2> = Matrix::from(vec![vec![2.0, 1.0], vec![1.0, 2.0]]);
+        let eigenvalues = symmetric.eigenvalues();
+        assert!((eigenvalues[0] - Complex::new(3.0, 0.0)).norm() < 1e-12);
+        assert!((eigenvalues[1] - Complex::new(1.0, 0.0)).norm() < 1e-12);
+
+        // a quarter turn has eigenvalues ±i
+        let rotation: Matrix<f64, 2, 2> = Matrix::rotation_2d(std::f64::consts::FRAC_PI_2);
+        let eigenvalues = rotation.eigenvalues();
+        assert!((eigenvalues[0] - Complex::new(0.0, 1.0)).norm() < 1e-12);
+        assert!((eigenvalues[1] - Complex::new(0.0, -1.0)).norm() < 1e-12);
+
+        let general: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![4.0, 1.0, 2.0],
+            vec![2.0, 3.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+        ]);
+        let mut eigenvalues: Vec<f64> = general.eigenvalues().iter().map(|e| e.re).collect();
+        eigenvalues.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        for (e, x) in eigenvalues.iter().zip([5.0, 2.0, 1.0]) {
+            assert!((e - x).abs() < 1e-9);
+        }
+
+        // non-finite entries give NaN eigenvalues instead of a failed iteration
+        let mut poisoned = general;
+        poisoned[(1, 2)] = f64::NAN;
+        let eigenvalues = poisoned.eigenvalues();
+        assert_eq!(eigenvalues.len(), 3);
+        assert!(eigenvalues.iter().all(|e| e.is_nan()));
+        poisoned[(1, 2)] = f64::INFINITY;
+        assert!(poisoned.eigenvalues().iter().all(|e| e.is_nan()));
+    }
+
+    #[test]
+    #[cfg(feature = "complex")]
+    fn eigenvalues_qr_complex_pair() {
+        use num_complex::Complex;
+
+        // the companion matrix of (λ - 1)(λ² - 2λ + 2), with eigenvalues 1 and 1 ± i
+        let m: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+            vec![2.0, -4.0, 3.0],
+        ]);
+        let eigenvalues = m.eigenvalues_qr(100).unwrap();
+        assert_eq!(eigenvalues.len(), 3);
+        for expected in [
+            Complex::new(1.0, 0.0),
+            Complex::new(1.0, 1.0),
+            Complex::new(1.0, -1.0),
+        ] {
+            assert!(eigenvalues.iter().any(|e| (e - expected).norm() < 1e-9));
+        }
+    }
+
+    #[test]
+    fn commutator_is_traceless() {
+        use num_traits::Zero;
+
+        let a: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![1.0, 2.5, -3.0],
+            vec![0.5, 4.0, 1.0],
+            vec![2.0, -1.0, 0.25],
+        ]);
+        let b: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![0.0, 1.0, 2.0],
+            vec![-1.5, 3.0, 0.5],
+            vec![1.0, 1.0, -2.0],
+        ]);
+        let commutator = a.commutator(&b);
+        assert!(commutator.trace().abs() < 1e-12);
+        assert!(!commutator.is_zero());
+        assert_eq!(commutator, -b.commutator(&a));
+
+        // a matrix commutes with itself
+        assert!(a.commutator(&a).is_zero());
+    }
+
+    #[test]
+    fn anticommutator() {
+        let a: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+        let b: Matrix<i32, 2, 2> = Matrix::from(vec![vec![0, 1], vec![1, 0]]);
+        // AB = [[2, 1], [4, 3]] and BA = [[3, 4], [1, 2]]
+        assert_eq!(
+            a.anticommutator(&b),
+            Matrix::from(vec![vec![5, 5], vec![5, 5]])
+        );
+        assert_eq!(a.anticommutator(&b), b.anticommutator(&a));
+
+        // the Pauli matrices X and Z anticommute
+        let z: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 0], vec![0, -1]]);
+        assert_eq!(b.anticommutator(&z), Matrix::zeroes(2, 2));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index out of bounds: tried to access (2, 0) but matrix has 2 rows and 3 columns."
+    )]
+    fn index_row_out_of_bounds() {
+        let m: Matrix<i32, 2, 3> = Matrix::zeroes(2, 3);
+        let _ = m[(2, 0)];
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index out of bounds: tried to access (1, 3) but matrix has 2 rows and 3 columns."
+    )]
+    fn index_mut_col_out_of_bounds() {
+        let mut m: Matrix<i32, 2, 3> = Matrix::zeroes(2, 3);
+        m[(1, 3)] = 1;
+    }
+
+    #[test]
+    fn fold_with_coordinates() {
+        let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+        // weighs every entry by (row + 1) * (column + 1)
+        let weighted = m.fold_indexed(0, |acc, (x, y), e| {
+            acc + (x as i32 + 1) * (y as i32 + 1) * e
+        });
+        assert_eq!(weighted, 1 + 2 * 2 + 2 * 3 + 4 * 4);
+
+        let positions = m.fold_indexed(Vec::new(), |mut acc, index, _| {
+            acc.push(index);
+            acc
+        });
+        assert_eq!(positions, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+    }
+
+    #[test]
+    fn scalar_add_and_sub() {
+        let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(m + 1, Matrix::from(vec![vec![2, 3], vec![4, 5]]));
+        assert_eq!(m - 1, Matrix::from(vec![vec![0, 1], vec![2, 3]]));
+
+        // matrix-matrix addition still works alongside
+        assert_eq!(m + m - 1, Matrix::from(vec![vec![1, 3], vec![5, 7]]));
+
+        let f: Matrix<f64, 1, 2> = Matrix::from(vec![vec![0.5, 1.5]]);
+        assert_eq!(f - 0.5, Matrix::from(vec![vec![0.0, 1.0]]));
+    }
+
+    #[test]
+    fn determinant_base_cases() {
+        let empty: Matrix<i32, 0, 0> = Matrix::from_array([]);
+        assert_eq!(empty.determinant(), 1);
+
+        let single: Matrix<i32, 1, 1> = Matrix::from_array([-7]);
+        assert_eq!(single.determinant(), -7);
+    }
+
+    #[test]
+    fn companion_matrix() {
+        // x² - 5x + 6 = (x - 2)(x - 3)
+        let m: Matrix<f64, 2, 2> = Matrix::companion(&[6.0, -5.0]);
+        assert_eq!(m, Matrix::from(vec![vec![0.0, 1.0], vec![-6.0, 5.0]]));
+        // the eigenvalues sum to the trace and multiply to the determinant
+        assert_eq!(m.trace(), 5.0);
+        assert_eq!(m.determinant(), 6.0);
+
+        #[cfg(feature = "complex")]
+        {
+            let eigenvalues = m.eigenvalues();
+            for root in [2.0, 3.0] {
+                assert!(eigenvalues
+                    .iter()
+                    .any(|e| (e.re - root).abs() < 1e-12 && e.im == 0.0));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "complex")]
+    fn polynomial_roots() {
+        use num_complex::Complex;
+
+        // 2(x - 1)(x - 2)(x + 3) = 2x³ - 14x + 12
+        let found = crate::roots(&[12.0, -14.0, 0.0, 2.0]);
+        assert_eq!(found.len(), 3);
+        for root in [1.0, 2.0, -3.0] {
+            assert!(found
+                .iter()
+                .any(|r| (r - Complex::new(root, 0.0)).norm() < 1e-9));
+        }
+
+        // x² + 1
+        let found = crate::roots(&[1.0, 0.0, 1.0]);
+        for root in [Complex::new(0.0, 1.0), Complex::new(0.0, -1.0)] {
+            assert!(found.iter().any(|r| (r - root).norm() < 1e-12));
+        }
+
+        // x³ - 1 and x⁴ - 1, whose roots are the roots of unity
+        let half_root_three = 3.0f64.sqrt() / 2.0;
+        let found = crate::roots(&[-1.0, 0.0, 0.0, 1.0]);
+        for root in [
+            Complex::new(1.0, 0.0),
+            Complex::new(-0.5, half_root_three),
+            Complex::new(-0.5, -half_root_three),
+        ] {
+            assert!(found.iter().any(|r| (r - root).norm() < 1e-12));
+        }
+        let found = crate::roots(&[-1.0, 0.0, 0.0, 0.0, 1.0]);
+        for root in [
+            Complex::new(1.0, 0.0),
+            Complex::new(-1.0, 0.0),
+            Complex::new(0.0, 1.0),
+            Complex::new(0.0, -1.0),
+        ] {
+            assert!(found.iter().any(|r| (r - root).norm() < 1e-12));
+        }
+    }
+
+    #[test]
+    fn diagonal_classification() {
+        let diagonal: Matrix<f64, 3, 3> = Matrix::scaling(&[2.0, -3.0]);
+        assert!(diagonal.is_diagonal(0.0));
+        assert!(diagonal.is_diagonally_dominant());
+
+        let dominant: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![4.0, -1.0, 2.0],
+            vec![1.0, -5.0, 3.0],
+            vec![0.5, 0.5, 1.5],
+        ]);
+        assert!(!dominant.is_diagonal(1e-12));
+        assert!(dominant.is_diagonally_dominant());
+
+        // the second row only reaches equality
+        let not_dominant: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![4.0, -1.0, 2.0],
+            vec![2.0, 5.0, 3.0],
+            vec![0.5, 0.5, 1.5],
+        ]);
+        assert!(!not_dominant.is_diagonally_dominant());
+
+        let nearly_diagonal: Matrix<f64, 2, 2> =
+            Matrix::from(vec![vec![1.0, 1e-14], vec![0.0, 1.0]]);
+        assert!(nearly_diagonal.is_diagonal(1e-12));
+        assert!(!nearly_diagonal.is_diagonal(0.0));
+    }
+
+    #[test]
+    fn iterative_solvers() {
+        let m: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![10.0, -1.0, 2.0],
+            vec![-1.0, 11.0, -1.0],
+            vec![2.0, -1.0, 10.0],
+        ]);
+        let b: Matrix<f64, 3, 1> = Matrix::from(vec![vec![6.0], vec![25.0], vec![-11.0]]);
+        let direct = m.solve(&b).unwrap();
+
+        let jacobi = m.solve_jacobi(&b, 100, 1e-12).unwrap();
+        assert!(jacobi.approx_eq(&direct, 1e-10));
+        let gauss_seidel = m.solve_gauss_seidel(&b, 100, 1e-12).unwrap();
+        assert!(gauss_seidel.approx_eq(&direct, 1e-10));
+
+        // too few iterations to reach the tolerance
+        assert_eq!(m.solve_jacobi(&b, 2, 1e-12), None);
+
+        // not diagonally dominant, and diverging
+        let divergent: Matrix<f64, 2, 2> = Matrix::from(vec![vec![1.0, 3.0], vec![2.0, 1.0]]);
+        let b: Matrix<f64, 2, 1> = Matrix::from(vec![vec![1.0], vec![1.0]]);
+        assert_eq!(divergent.solve_gauss_seidel(&b, 50, 1e-12), None);
+    }
+
+    #[test]
+    fn residuals() {
+        let m: Matrix<f64, 2, 2> = Matrix::from(vec![vec![2.0, 1.0], vec![1.0, 3.0]]);
+        let b: Matrix<f64, 2, 1> = Matrix::from(vec![vec![3.0], vec![5.0]]);
+        let exact = m.solve(&b).unwrap();
+        assert!(m.residual_norm(&exact, &b) < 1e-12);
+
+        let guess: Matrix<f64, 2, 1> = Matrix::from(vec![vec![0.0], vec![0.0]]);
+        assert_eq!(m.residual(&guess, &b), b);
+        assert!((m.residual_norm(&guess, &b) - 34.0_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    #[cfg(feature = "approx")]
+    fn approx_comparisons() {
+        use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+
+        let m: Matrix<f64, 2, 2> = Matrix::from(vec![vec![4.0, 7.0], vec![2.0, 6.0]]);
+        let round_trip = m.inverse().unwrap().inverse().unwrap();
+
+        assert_abs_diff_eq!(round_trip, m, epsilon = 1e-9);
+        assert_relative_eq!(round_trip, m, max_relative = 1e-12);
+        assert_ulps_eq!(round_trip, m, max_ulps = 8);
+        assert_relative_ne!(m.scalar_mul(1.001), m, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn invertibility_checks() {
+        let invertible: Matrix<i32, 2, 2> = Matrix::from(vec![vec![2, 1], vec![1, 1]]);
+        let singular: Matrix<i32, 2, 2> = Matrix::from(vec![vec![2, 4], vec![1, 2]]);
+        assert!(invertible.is_invertible());
+        assert!(!singular.is_invertible());
+
+        let nearly_singular: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![0.1, 0.2, 0.3],
+            vec![0.4, 0.5, 0.6],
+            vec![0.7, 0.8, 0.9],
+        ]);
+        assert!(!nearly_singular.is_invertible_within(1e-12));
+        let well_conditioned: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![4.0, 1.0, 0.0],
+            vec![1.0, 4.0, 1.0],
+            vec![0.0, 1.0, 4.0],
+        ]);
+        assert!(well_conditioned.is_invertible_within(1e-12));
+
+        let nan: Matrix<f64, 2, 2> = Matrix::from(vec![vec![1.0, 2.0], vec![f64::NAN, 4.0]]);
+        assert!(!nan.is_invertible_within(1e-12));
+    }
+
+    #[test]
+    fn string_grid() {
+        let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, -20], vec![300, 4]]);
+        assert_eq!(m.to_string_grid("\t", "\n"), "1\t-20\n300\t4");
+
+        let row: Matrix<f64, 1, 3> = Matrix::from(vec![vec![0.5, 1.0, 2.25]]);
+        assert_eq!(row.to_string_grid(" | ", "\n"), "0.5 | 1 | 2.25");
+
+        let empty_rows: Matrix<i32, 3, 0> = Matrix::from_array([]);
+        assert_eq!(empty_rows.to_string_grid(", ", ";"), ";;");
+    }
+
+    #[test]
+    fn iterator_lengths() {
+        let mut m: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(m.iter().len(), 6);
+        assert_eq!(m.iter_indexed().len(), 6);
+        assert_eq!(m.iter_mut().len(), 6);
+
+        let mut indexed = m.iter_indexed();
+        indexed.next();
+        assert_eq!(indexed.len(), 5);
+    }
+
+    #[test]
+    fn permute_rows_and_cols() {
+        let m: Matrix<i32, 3, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+
+        // the cycle 0 -> 1 -> 2 -> 0, as two swaps
+        let mut swapped = m;
+        swapped.row_swap(0, 1);
+        swapped.row_swap(1, 2);
+        assert_eq!(m.permute_rows(&[1, 2, 0]), swapped);
+        assert_eq!(
+            m.permute_cols(&[1, 0]),
+            Matrix::from(vec![vec![2, 1], vec![4, 3], vec![6, 5]])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a permutation")]
+    fn permute_rows_rejects_duplicates() {
+        let m: Matrix<i32, 3, 2> = Matrix::zeroes(3, 2);
+        let _ = m.permute_rows(&[0, 0, 1]);
+    }
+
+    #[test]
+    fn matrix_square_root() {
+        // symmetric positive definite
+        let spd: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![4.0, 1.0, 0.5],
+            vec![1.0, 3.0, 0.0],
+            vec![0.5, 0.0, 2.0],
+        ]);
+        let root = spd.sqrtm().unwrap();
+        assert!((root * root).approx_eq(&spd, 1e-12));
+        assert!(root.approx_eq(&root.transpose(), 1e-12));
+
+        // not symmetric
+        let general: Matrix<f64, 2, 2> = Matrix::from(vec![vec![4.0, 1.0], vec![0.0, 9.0]]);
+        let root = general.sqrtm().unwrap();
+        assert!((root * root).approx_eq(&general, 1e-12));
+
+        // a negative eigenvalue rules out a real square root
+        let indefinite: Matrix<f64, 2, 2> = Matrix::from(vec![vec![1.0, 2.0], vec![2.0, 1.0]]);
+        assert_eq!(indefinite.sqrtm(), None);
+    }
+
+    #[test]
+    fn one_by_one_from_scalar() {
+        let m: Matrix<i32, 1, 1> = Matrix::from(7);
+        assert_eq!(m[(0, 0)], 7);
+        let m: Matrix<f64, 1, 1> = 2.5.into();
+        assert_eq!(m, Matrix::from_array([2.5]));
+    }
+
+    #[test]
+    fn scalar_extraction() {
+        let row: RowVector<f64, 3> = Matrix::from(vec![1.0, -2.0, 0.5]);
+        let col: ColVector<f64, 3> = Matrix::from(vec![4.0, 1.0, 2.0]);
+        assert_eq!((row * col).scalar(), 3.0);
+        assert_eq!(Matrix::from(9u8).scalar(), 9);
+    }
+
+    #[test]
+    fn rank1_update() {
+        let a: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let u: ColVector<i32, 2> = Matrix::from(vec![2, -1]);
+        let v: RowVector<i32, 3> = Matrix::from(vec![1, 0, 3]);
+
+        let mut updated = a;
+        updated.rank1_update(&u, &v, 1);
+        assert_eq!(updated, a + u * v);
+
+        let mut updated = a;
+        updated.rank1_update(&u, &v, -3);
+        assert_eq!(updated, a + (u * v).scalar_mul(-3));
+    }
+
+    #[test]
+    fn direct_sum() {
+        let a: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+        let b: Matrix<i32, 1, 1> = Matrix::from(vec![vec![5]]);
+        let sum: Matrix<i32, 3, 3> = a.direct_sum(&b);
+        assert_eq!(
+            sum,
+            Matrix::from(vec![vec![1, 2, 0], vec![3, 4, 0], vec![0, 0, 5]])
+        );
+        // the off-diagonal blocks are zero
+        assert!((0..2).all(|i| sum[(i, 2)] == 0 && sum[(2, i)] == 0));
+    }
+
+    #[test]
+    fn reduce_along_axis() {
+        let m: Matrix<f64, 2, 3> = Matrix::from(vec![vec![1.0, 7.5, -3.0], vec![4.0, 2.0, 6.0]]);
+        assert_eq!(
+            m.reduce_axis(Axis::Rows, f64::NEG_INFINITY, f64::max),
+            vec![7.5, 6.0]
+        );
+        assert_eq!(
+            m.reduce_axis(Axis::Cols, 0.0, |a, b| a + b),
+            m.col_sums().iter().copied().collect::<Vec<f64>>()
+        );
+    }
+
+    #[test]
+    fn bilinear_and_quadratic_forms() {
+        // positive definite: xᵀAx = 2x₀² + 2x₀x₁ + 3x₁² > 0 for x ≠ 0
+        let a: Matrix<i32, 2, 2> = Matrix::from(vec![vec![2, 1], vec![1, 3]]);
+        let x: ColVector<i32, 2> = Matrix::from(vec![3, -2]);
+        let y: ColVector<i32, 2> = Matrix::from(vec![1, 4]);
+        assert_eq!(a.quadratic_form(&x), 18 - 12 + 12);
+        assert_eq!(a.bilinear_form(&x, &y), (x.transpose() * a * y).scalar());
+
+        let b: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 0, 2], vec![-1, 3, 1]]);
+        let z: ColVector<i32, 3> = Matrix::from(vec![2, 1, 1]);
+        assert_eq!(b.bilinear_form(&x, &z), (x.transpose() * b * z).scalar());
+    }
+
+    #[test]
+    fn positive_definiteness() {
+        let spd: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![4.0, 2.0, 0.0],
+            vec![2.0, 5.0, 1.0],
+            vec![0.0, 1.0, 3.0],
+        ]);
+        let l = spd.cholesky().unwrap();
+        assert!((l * l.transpose()).approx_eq(&spd, 1e-12));
+        assert!(spd.is_positive_definite());
+        assert!(spd.is_positive_semidefinite());
+
+        let indefinite: Matrix<f64, 2, 2> = Matrix::from(vec![vec![1.0, 2.0], vec![2.0, 1.0]]);
+        assert!(!indefinite.is_positive_definite());
+        assert!(!indefinite.is_positive_semidefinite());
+
+        // xxᵀ for x = (1, 2): positive semidefinite, but singular
+        let singular: Matrix<f64, 2, 2> = Matrix::from(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+        assert!(!singular.is_positive_definite());
+        assert!(singular.is_positive_semidefinite());
+    }
+
+    #[test]
+    fn scalar_assign_ops() {
+        let mut m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+        m += 2;
+        assert_eq!(m, Matrix::from(vec![vec![3, 4], vec![5, 6]]));
+        m -= 1;
+        assert_eq!(m, Matrix::from(vec![vec![2, 3], vec![4, 5]]));
+        m *= 3;
+        assert_eq!(m, Matrix::from(vec![vec![6, 9], vec![12, 15]]));
+        m /= 4;
+        assert_eq!(m, Matrix::from(vec![vec![1, 2], vec![3, 3]]));
+    }
+
+    #[test]
+    fn borrowed_views() {
+        let mut m: Matrix<i32, 3, 4> = Matrix::from_fn(|x, y| (10 * x + y) as i32);
+        {
+            let v = m.view(1, 2, 2, 2);
+            assert_eq!((v.rows(), v.cols()), (2, 2));
+            assert_eq!(
+                [v[(0, 0)], v[(0, 1)], v[(1, 0)], v[(1, 1)]],
+                [12, 13, 22, 23]
+            );
+            assert_eq!(v.transpose()[(0, 1)], 22);
+            assert_eq!(
+                v.to_matrix::<2, 2>(),
+                Matrix::from(vec![vec![12, 13], vec![22, 23]])
+            );
+        }
+
+        m[(2, 3)] = -1;
+        assert_eq!(m.view(1, 2, 2, 2)[(1, 1)], -1);
+        assert_eq!(m.as_view()[(2, 3)], -1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn view_out_of_bounds() {
+        let m: Matrix<i32, 2, 2> = Matrix::zeroes(2, 2);
+        m.view(1, 1, 2, 1);
+    }
+
+    #[test]
+    fn into_rows() {
+        let m: Matrix<i32, 3, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+        let rows = m.into_rows();
+        assert_eq!(rows.len(), 3);
+        let rows: Vec<RowVector<i32, 2>> = rows.collect();
+        assert_eq!(
+            rows,
+            vec![
+                Matrix::from(vec![1, 2]),
+                Matrix::from(vec![3, 4]),
+                Matrix::from(vec![5, 6])
+            ]
+        );
+    }
+
+    #[test]
+    fn sherman_morrison_update() {
+        let a: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![4.0, 1.0, 0.0],
+            vec![1.0, 3.0, 1.0],
+            vec![0.0, 1.0, 2.0],
+        ]);
+        let inv = a.inverse().unwrap();
+        let u: ColVector<f64, 3> = Matrix::from(vec![1.0, 0.0, 2.0]);
+        let v: RowVector<f64, 3> = Matrix::from(vec![0.5, -1.0, 1.0]);
+
+        let updated = a.inverse_update_rank1(&inv, &u, &v).unwrap();
+        assert!(updated.approx_eq(&(a + u * v).inverse().unwrap(), 1e-12));
+
+        // A + uv is singular when 1 + vA⁻¹u = 0
+        let identity: Matrix<f64, 2, 2> = Matrix::identity(2);
+        let u: ColVector<f64, 2> = Matrix::from(vec![1.0, 0.0]);
+        let v: RowVector<f64, 2> = Matrix::from(vec![-1.0, 0.0]);
+        assert_eq!(identity.inverse_update_rank1(&identity, &u, &v), None);
+    }
+
+    #[test]
+    fn identity_check() {
+        let identity: Matrix<f64, 3, 3> = Matrix::identity(3);
+        assert!(identity.is_identity(0.0));
+
+        let a: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![2.0, 1.0, 0.0],
+            vec![1.0, 3.0, 1.0],
+            vec![0.0, 1.0, 4.0],
+        ]);
+        assert!((a * a.inverse().unwrap()).is_identity(1e-12));
+        let (q, _) = a.qr();
+        assert!((q.transpose() * q).is_identity(1e-12));
+
+        let mut perturbed = identity;
+        perturbed[(0, 2)] = 1e-3;
+        assert!(perturbed.is_identity(1e-2));
+        assert!(!perturbed.is_identity(1e-4));
+        assert!(!a.is_identity(1e-12));
+    }
+
+    #[test]
+    fn pivot_strategies() {
+        let a: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![4.0, -1.0, 1.0],
+            vec![2.0, 5.0, -1.0],
+            vec![1.0, 1.0, 6.0],
+        ]);
+        let x: ColVector<f64, 3> = Matrix::from(vec![1.0, -2.0, 3.0]);
+        let b = a * x;
+        for strategy in [
+            PivotStrategy::None,
+            PivotStrategy::Partial,
+            PivotStrategy::Complete,
+        ] {
+            let lu = a.lu_with(strategy).unwrap();
+            assert!((lu.lower * lu.upper).approx_eq(
+                &a.permute_rows(&lu.row_perm).permute_cols(&lu.col_perm),
+                1e-12
+            ));
+            assert!((lu.determinant() - a.determinant()).abs() < 1e-10);
+            assert!(a.solve_with(&b, strategy).unwrap().approx_eq(&x, 1e-12));
+            assert!((a * a.inverse_with(strategy).unwrap()).is_identity(1e-12));
+        }
+
+        // Partial pivoting only looks at the first column, where both candidates are
+        // equal, so the huge entry next to the pivot swamps the second row.
+        let big = 1e18;
+        let a: Matrix<f64, 2, 2> = Matrix::from(vec![vec![1.0, big], vec![1.0, 1.0]]);
+        let b: ColVector<f64, 2> = Matrix::from(vec![1.0 + big, 2.0]);
+        let exact: ColVector<f64, 2> = Matrix::from(vec![1.0, 1.0]);
+        let partial = a.solve_with(&b, PivotStrategy::Partial).unwrap();
+        let complete = a.solve_with(&b, PivotStrategy::Complete).unwrap();
+        assert!(!partial.approx_eq(&exact, 0.5));
+        assert!(complete.approx_eq(&exact, 1e-12));
+
+        // without pivoting a zero pivot is fatal
+        let swapped: Matrix<f64, 2, 2> = Matrix::from(vec![vec![0.0, 1.0], vec![1.0, 0.0]]);
+        assert!(swapped.lu_with(PivotStrategy::None).is_none());
+        assert!(swapped.lu().is_some());
+    }
+
+    #[test]
+    fn fallible_construction() {
+        let m: Result<Matrix<usize, 2, 3>, String> =
+            Matrix::try_from_closure(2, 3, |x, y| Ok(x * 3 + y));
+        assert_eq!(m, Ok(Matrix::from(vec![vec![0, 1, 2], vec![3, 4, 5]])));
+
+        let m: Result<Matrix<usize, 2, 3>, String> = Matrix::try_from_closure(2, 3, |x, y| {
+            if (x, y) == (1, 1) {
+                Err(format!("bad cell at ({x}, {y})"))
+            } else {
+                Ok(x + y)
+            }
+        });
+        assert_eq!(m, Err("bad cell at (1, 1)".to_string()));
+    }
+
+    #[test]
+    fn bareiss_determinant() {
+        let m: Matrix<i64, 4, 4> = Matrix::from(vec![
+            vec![3, 2, -1, 4],
+            vec![2, 1, 5, 7],
+            vec![0, 5, 2, -6],
+            vec![-1, 2, 1, 0],
+        ]);
+        // Laplace expansion along the first row
+        let cofactors = m.comatrix();
+        let laplace: i64 = (0..4).map(|j| m[(0, j)] * cofactors[(0, j)]).sum();
+        assert_eq!(m.determinant_bareiss(), laplace);
+        assert_eq!(m.determinant_bareiss(), -418);
+
+        // needs a row swap, which flips the sign
+        let swapped: Matrix<i64, 3, 3> =
+            Matrix::from(vec![vec![0, 1, 2], vec![1, 0, 3], vec![4, -3, 8]]);
+        assert_eq!(swapped.determinant_bareiss(), swapped.determinant());
+
+        let singular: Matrix<i64, 3, 3> =
+            Matrix::from(vec![vec![1, 2, 3], vec![2, 4, 6], vec![0, 1, 1]]);
+        assert_eq!(singular.determinant_bareiss(), 0);
+    }
+
+    #[test]
+    fn symmetric_and_skew_parts() {
+        let m: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![1.0, 2.0, -3.0],
+            vec![4.0, 5.0, 0.5],
+            vec![7.0, -8.0, 9.0],
+        ]);
+        let symmetric = m.symmetric_part();
+        let skew = m.skew_part();
+        assert_eq!(symmetric + skew, m);
+        assert!(symmetric.is_symmetric());
+        assert!(skew.is_skew_symmetric());
+        assert_eq!(symmetric[(0, 1)], 3.0);
+        assert_eq!(skew[(0, 1)], -1.0);
+    }
+
+    #[test]
+    fn value_counts() {
+        let labels: Matrix<char, 3, 3> = Matrix::from(vec![
+            vec!['a', 'b', 'a'],
+            vec!['c', 'a', 'b'],
+            vec!['a', 'a', 'c'],
+        ]);
+        let counts = labels.value_counts();
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&'a'], 5);
+        assert_eq!(counts[&'b'], 2);
+        assert_eq!(counts[&'c'], 2);
+        assert_eq!(counts.values().sum::<usize>(), 9);
+    }
+
+    #[test]
+    fn dimensions_come_from_the_type() {
+        let m: Matrix<u8, 2, 3> = Matrix {
+            data: [1, 2, 3, 4, 5, 6],
+        };
+        assert_eq!((m.rows(), m.cols()), (2, 3));
+        assert_eq!(m.transpose().rows(), 3);
+        assert!(!m.is_square());
+        assert_eq!(std::mem::size_of::<Matrix<u8, 2, 3>>(), 6);
+
+        assert_eq!(m, Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+        assert_eq!(m.to_string(), "1 2 3 \n4 5 6 \n");
+        assert_eq!(m + m, m.scalar_mul(2));
+        assert_eq!(m.submatrix(0, 1), Matrix::from(vec![vec![4, 6]]));
+    }
+
+    #[test]
+    fn vector_interop() {
+        let mut v = Vector::from_array([1, -2, 3]);
+        assert_eq!(v.len(), 3);
+        assert_eq!(v[1], -2);
+        v[1] = 2;
+
+        let m: Matrix<i32, 2, 3> = Matrix::from(vec![vec![1, 0, 2], vec![0, 3, -1]]);
+        let col: ColVector<i32, 3> = v.into();
+        assert_eq!(m * v, Vector::from(m * col));
+        assert_eq!(m * v, Vector::from([7, 3]));
+        assert_eq!(Vector::from(col), v);
+        assert_eq!(v.dot(&v), 14);
+    }
+
+    #[test]
+    fn scalar_mul_div_operators() {
+        let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, -2], vec![3, 4]]);
+        let tripled = Matrix::from(vec![vec![3, -6], vec![9, 12]]);
+        assert_eq!(m * 3, tripled);
+        assert_eq!(m.scale(3), tripled);
+        assert_eq!(tripled / 3, m);
+        assert_eq!(tripled.unscale(3), m);
+        assert_eq!(m / 2, Matrix::from(vec![vec![0, -1], vec![1, 2]]));
+
+        let f: Matrix<f64, 1, 2> = Matrix::from(vec![1.0, 3.0]);
+        assert_eq!(0.5 * f, f / 2.0);
+        assert_eq!(2.0 * f, f * 2.0);
+    }
+
+    #[test]
+    fn negation() {
+        let m: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, -2], vec![0, 4]]);
+        assert_eq!(-m, Matrix::from(vec![vec![-1, 2], vec![0, -4]]));
+        assert_eq!(-(-m), m);
+
+        // only `Neg` is required of the element type
+        #[derive(Debug, PartialEq)]
+        struct Charge(String);
+        impl std::ops::Neg for Charge {
+            type Output = Charge;
+            fn neg(self) -> Charge {
+                Charge(format!("-{}", self.0))
+            }
+        }
+        let charges: Matrix<Charge, 1, 2> = Matrix::from_fn(|_, y| Charge(format!("q{y}")));
+        assert_eq!((-charges)[(0, 1)], Charge("-q1".to_string()));
+    }
+
+    #[test]
+    fn component_wise_mul_div() {
+        let a: Matrix<f64, 2, 2> = Matrix::from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        let b: Matrix<f64, 2, 2> = Matrix::from(vec![vec![2.0, 0.5], vec![-1.0, 4.0]]);
+        let product = a.component_mul(&b);
+        assert_eq!(
+            product,
+            Matrix::from(vec![vec![2.0, 1.0], vec![-3.0, 16.0]])
+        );
+        assert_eq!(product.component_div(&b), a);
+
+        // masking with zeroes and ones
+        let mask: Matrix<f64, 2, 2> = Matrix::identity(2);
+        assert_eq!(
+            a.component_mul(&mask),
+            Matrix::from(vec![vec![1.0, 0.0], vec![0.0, 4.0]])
+        );
+    }
+
+    #[test]
+    fn kronecker_product() {
+        let a: Matrix<i32, 2, 2> = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+        let b: Matrix<i32, 2, 3> = Matrix::from(vec![vec![0, 5, 1], vec![6, 7, 0]]);
+        let k: Matrix<i32, 4, 6> = a.kronecker(&b);
+        assert_eq!(
+            k,
+            Matrix::from(vec![
+                vec![0, 5, 1, 0, 10, 2],
+                vec![6, 7, 0, 12, 14, 0],
+                vec![0, 15, 3, 0, 20, 4],
+                vec![18, 21, 0, 24, 28, 0],
+            ])
+        );
+
+        // the identity factor yields block diagonal copies, which traces back out
+        let i2: Matrix<i32, 2, 2> = Matrix::identity(2);
+        let blocks: Matrix<i32, 4, 4> = i2.kronecker(&a);
+        assert_eq!(blocks.trace(), 2 * a.trace());
+        let traced: Matrix<i32, 2, 2> = a.kronecker(&i2).partial_trace::<2>();
+        assert_eq!(traced, a.scale(2));
+    }
+
+    #[test]
+    fn matrix_power() {
+        let m: Matrix<i64, 3, 3> = Matrix::from(vec![vec![1, 2, 0], vec![0, 1, -1], vec![3, 0, 2]]);
+        assert_eq!(m.pow(0), Matrix::identity(3));
+        assert_eq!(m.pow(1), m);
+        let mut naive = m;
+        for _ in 1..13 {
+            naive = naive * m;
+        }
+        assert_eq!(m.pow(13), naive);
+
+        // counting walks: paths of length 3 in the triangle graph
+        let triangle: Matrix<u32, 3, 3> =
+            Matrix::from(vec![vec![0, 1, 1], vec![1, 0, 1], vec![1, 1, 0]]);
+        assert_eq!(triangle.pow(3)[(0, 0)], 2);
+
+        // the largest Fibonacci number that fits in a u64
+        let fibonacci: Matrix<u64, 2, 2> = Matrix::from(vec![vec![1, 1], vec![1, 0]]);
+        assert_eq!(fibonacci.pow(92)[(0, 0)], 12200160415121876738);
+    }
+
+    #[test]
+    fn determinant_of_larger_matrices() {
+        // triangular: the product of the diagonal
+        let triangular: Matrix<i64, 4, 4> = Matrix::from(vec![
+            vec![2, 1, 7, -3],
+            vec![0, 3, 1, 4],
+            vec![0, 0, -1, 5],
+            vec![0, 0, 0, 4],
+        ]);
+        assert_eq!(triangular.determinant(), -24);
+
+        // integer entries stay exact, even when the leading entry is zero
+        let m: Matrix<i64, 5, 5> = Matrix::from(vec![
+            vec![0, 2, -1, 3, 1],
+            vec![4, 1, 0, 2, -2],
+            vec![1, -3, 2, 0, 5],
+            vec![2, 0, 1, 1, 1],
+            vec![-1, 4, 3, -2, 0],
+        ]);
+        let cofactors = m.comatrix();
+        let laplace: i64 = (0..5).map(|j| m[(0, j)] * cofactors[(0, j)]).sum();
+        assert_eq!(m.determinant(), laplace);
+
+        // a repeated row makes it singular
+        let mut singular = m;
+        for y in 0..5 {
+            singular[(4, y)] = singular[(1, y)];
+        }
+        assert_eq!(singular.determinant(), 0);
+
+        let f: Matrix<f64, 5, 5> = Matrix::from_fn(|x, y| m[(x, y)] as f64);
+        assert!((f.determinant() - laplace as f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn inverse_of_larger_matrices() {
+        let m: Matrix<f64, 5, 5> = Matrix::from(vec![
+            vec![0.0, 2.0, -1.0, 3.0, 1.0],
+            vec![4.0, 1.0, 0.0, 2.0, -2.0],
+            vec![1.0, -3.0, 2.0, 0.0, 5.0],
+            vec![2.0, 0.0, 1.0, 1.0, 1.0],
+            vec![-1.0, 4.0, 3.0, -2.0, 0.0],
+        ]);
+        let inverse = m.inverse().unwrap();
+        assert!((m * inverse).is_identity(1e-12));
+        assert!((inverse * m).is_identity(1e-12));
+        assert!(inverse.approx_eq(&m.try_inverse().unwrap(), 1e-12));
+
+        let mut singular = m;
+        for y in 0..5 {
+            singular[(4, y)] = singular[(0, y)] + singular[(1, y)];
+        }
+        assert_eq!(singular.inverse(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "complex")]
+    fn eigenvalues_qr_cyclic_permutation() {
+        use num_complex::Complex;
+
+        // an orthogonal Hessenberg matrix, which unshifted QR iteration leaves unchanged;
+        // its eigenvalues are the cube roots of unity
+        let m: Matrix<f64, 3, 3> = Matrix::from(vec![
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+            vec![1.0, 0.0, 0.0],
+        ]);
+        let eigenvalues = m.eigenvalues_qr(100).unwrap();
+        let half_root_three = 3.0f64.sqrt() / 2.0;
+        for expected in [
+            Complex::new(1.0, 0.0),
+            Complex::new(-0.5, half_root_three),
+            Complex::new(-0.5, -half_root_three),
+        ] {
+            assert!(eigenvalues.iter().any(|e| (e - expected).norm() < 1e-12));
+        }
+
+        // a budget of zero iterations isn't enough
+        assert!(m.eigenvalues_qr(0).is_none());
+    }
 }
@@ -0,0 +1,137 @@
+//! A dedicated vector type. `ColVector` and `RowVector` are just matrices with a single
+//! column or row, so working with them means carrying a useless second dimension around;
+//! `Vector` is a plain array of N elements that converts to and from a `ColVector`.
+
+use crate::{DimensionAssertion, IsTrue, Matrix};
+use num_traits::Zero;
+use std::ops::{Add, Index, IndexMut, Mul};
+
+/// A vector of N elements. Where it meets a `Matrix`, it acts as a column vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Vector<T, const N: usize> {
+    /// The elements of the vector.
+    pub data: [T; N],
+}
+
+impl<T, const N: usize> Vector<T, N> {
+    /// Constructs a vector directly from an array.
+    pub fn from_array(data: [T; N]) -> Self {
+        Vector { data }
+    }
+
+    /// Constructs a vector from a closure, which takes in the zero-indexed position of
+    /// every element.
+    pub fn from_fn<F>(func: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        Vector::from_array(std::array::from_fn(func))
+    }
+
+    /// Returns the number of elements in the vector.
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    /// Checks if the vector has no elements.
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns an iterator over the elements of the vector.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Calculates the dot product of two vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Vector;
+    /// let v = Vector::from_array([1, 2, 3]);
+    /// assert_eq!(v.dot(&Vector::from_array([4, 5, 6])), 32);
+    /// ```
+    pub fn dot<Q, R>(&self, other: &Vector<Q, N>) -> R
+    where
+        T: Copy + Mul<Q, Output = R>,
+        Q: Copy,
+        R: Zero,
+    {
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .fold(R::zero(), |acc, (&a, &b)| acc + a * b)
+    }
+}
+
+impl<T, const N: usize> Index<usize> for Vector<T, N> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(
+            index < N,
+            "Index out of bounds: tried to access element {index} but vector has {N} elements."
+        );
+        &self.data[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Vector<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        assert!(
+            index < N,
+            "Index out of bounds: tried to access element {index} but vector has {N} elements."
+        );
+        &mut self.data[index]
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
+    fn from(value: [T; N]) -> Self {
+        Vector::from_array(value)
+    }
+}
+
+#[allow(clippy::identity_op)]
+impl<T, const N: usize> From<Matrix<T, N, 1>> for Vector<T, N>
+where
+    [(); N * 1]:,
+{
+    /// Converts a column vector.
+    fn from(value: Matrix<T, N, 1>) -> Self {
+        let mut data = value.data.into_iter();
+        Vector::from_fn(|_| data.next().unwrap())
+    }
+}
+
+#[allow(clippy::identity_op)]
+impl<T, const N: usize> From<Vector<T, N>> for Matrix<T, N, 1>
+where
+    [(); N * 1]:,
+{
+    /// Converts into a column vector.
+    fn from(value: Vector<T, N>) -> Self {
+        let mut data = value.data.into_iter();
+        Matrix::from_array(std::array::from_fn(|_| data.next().unwrap()))
+    }
+}
+
+impl<T, Q, R, const N: usize, const M: usize, const O: usize> Mul<Vector<Q, O>> for Matrix<T, N, M>
+where
+    T: Copy + Mul<Q, Output = R>,
+    Q: Copy,
+    R: Add + Zero,
+    [(); N * M]:,
+    DimensionAssertion<{ M == O }>: IsTrue,
+{
+    type Output = Vector<R, N>;
+    /// Multiplies a matrix with a vector, treating the vector as a column vector.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Vector<Q, O>) -> Self::Output {
+        Vector::from_fn(|x| {
+            (0..M).fold(R::zero(), |dot_product, i| {
+                dot_product + self[(x, i)] * rhs.data[i]
+            })
+        })
+    }
+}
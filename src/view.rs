@@ -0,0 +1,113 @@
+//! Borrowed views into a matrix. Taking a `submatrix` copies the entries; a view only
+//! stores a reference to the parent's data together with the position and spacing of
+//! the region it looks at, so reading a block of a matrix doesn't copy anything.
+
+use crate::Matrix;
+use std::ops::Index;
+
+/// A read-only view of a rectangular region of a matrix. Entry `(r, c)` of the view is
+/// the element at `offset + r * row_stride + c * col_stride` of the parent's data.
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a, T> {
+    data: &'a [T],
+    offset: usize,
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    /// The number of rows the view spans.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The number of columns the view spans.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns a view of the transpose of this view. This only swaps the strides.
+    pub fn transpose(&self) -> MatrixView<'a, T> {
+        MatrixView {
+            rows: self.cols,
+            cols: self.rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+            ..*self
+        }
+    }
+
+    /// Copies the viewed region into a new matrix.
+    ///
+    /// ## Panics
+    ///
+    /// If the view doesn't span exactly P rows and Q columns.
+    pub fn to_matrix<const P: usize, const Q: usize>(&self) -> Matrix<T, P, Q>
+    where
+        T: Copy,
+        [(); P * Q]:,
+    {
+        assert!(
+            self.rows == P && self.cols == Q,
+            "Dimensionality does not hold: the view is {}×{} but Matrix type is {P}×{Q}.",
+            self.rows,
+            self.cols
+        );
+        Matrix::from_fn(|r, c| self[(r, c)])
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+    fn index(&self, (r, c): (usize, usize)) -> &Self::Output {
+        assert!(
+            r < self.rows && c < self.cols,
+            "Index out of bounds: tried to access ({r}, {c}) but view has {} rows and {} columns.",
+            self.rows,
+            self.cols
+        );
+        &self.data[self.offset + r * self.row_stride + c * self.col_stride]
+    }
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    [(); N * M]:,
+{
+    /// Borrows the block of `rows` by `cols` entries whose top-left corner is at
+    /// `(r, c)`, without copying it.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cayley::Matrix;
+    /// let m: Matrix<i32, 3, 3> = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    /// let v = m.view(1, 1, 2, 2);
+    /// assert_eq!((v[(0, 0)], v[(1, 1)]), (5, 9));
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// If the block doesn't fit inside the matrix.
+    pub fn view(&self, r: usize, c: usize, rows: usize, cols: usize) -> MatrixView<'_, T> {
+        assert!(
+            r + rows <= N && c + cols <= M,
+            "Index out of bounds: a {rows}×{cols} view at ({r}, {c}) doesn't fit in a matrix with {N} rows and {M} columns."
+        );
+        MatrixView {
+            data: &self.data,
+            offset: r * M + c,
+            rows,
+            cols,
+            row_stride: M,
+            col_stride: 1,
+        }
+    }
+
+    /// Borrows the whole matrix as a view.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        self.view(0, 0, N, M)
+    }
+}